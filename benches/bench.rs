@@ -0,0 +1,291 @@
+use base32::Alphabet;
+use bencher::{benchmark_group, benchmark_main, black_box, Bencher};
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
+use std::collections::HashMap;
+use std::slice;
+use std::time::{Duration, SystemTime};
+use ulys::{Generator, Ulys};
+use xxhash_rust::xxh3::xxh3_64;
+
+fn bench_new(b: &mut Bencher) {
+	b.iter(Ulys::new);
+}
+
+fn bench_new_fast(b: &mut Bencher) {
+	b.iter(Ulys::new_fast);
+}
+
+// The three components of `Ulys::new`, measured separately.
+
+fn bench_new_clock(b: &mut Bencher) {
+	b.iter(SystemTime::now);
+}
+
+fn bench_new_rng(b: &mut Bencher) {
+	let mut source = rand::rng();
+	b.iter(|| source.random::<u64>() & ((1 << Ulys::RAND_BITS) - 1));
+}
+
+// The random portion used to be drawn in two parts, kept for comparison.
+fn bench_new_rng_two_draws(b: &mut Bencher) {
+	let mut source = rand::rng();
+	b.iter(|| u64::from(source.random::<u16>()) << 32 | (source.random::<u64>() & 0xffff_ffff));
+}
+
+// Each iteration moves to the next millisecond, so that every generated Ulys
+// draws a new random portion instead of incrementing the previous one.
+
+fn bench_generate(b: &mut Bencher) {
+	let mut generator = Generator::new();
+	let mut dt = SystemTime::now();
+	b.iter(|| {
+		dt += Duration::from_millis(1);
+		generator.generate_from_datetime(dt)
+	});
+}
+
+fn bench_generate_with_rng(b: &mut Bencher) {
+	let mut generator = Generator::new().with_rng(SmallRng::seed_from_u64(42));
+	let mut dt = SystemTime::now();
+	b.iter(|| {
+		dt += Duration::from_millis(1);
+		generator.generate_from_datetime(dt)
+	});
+}
+
+fn bench_new_checksum(b: &mut Bencher) {
+	let data = Ulys::new().0 >> Ulys::CHECK_BITS << Ulys::CHECK_BITS;
+	b.iter(|| xxh3_64(&black_box(data).to_be_bytes()));
+}
+
+fn bench_join(b: &mut Bencher) {
+	let ids: Vec<Ulys> = (0..1000).map(|_| Ulys::new()).collect();
+	b.iter(|| Ulys::join(&ids, "\n"));
+}
+
+fn bench_join_to_string(b: &mut Bencher) {
+	let ids: Vec<Ulys> = (0..1000).map(|_| Ulys::new()).collect();
+	b.iter(|| {
+		ids.iter()
+			.map(ToString::to_string)
+			.collect::<Vec<_>>()
+			.join("\n")
+	});
+}
+
+fn bench_hashmap_ulys_keys(b: &mut Bencher) {
+	let ids: Vec<Ulys> = (0..1000).map(|_| Ulys::new()).collect();
+	b.iter(|| {
+		let mut map = HashMap::with_capacity(ids.len());
+		for (index, id) in ids.iter().enumerate() {
+			map.insert(*id, index);
+		}
+		ids.iter().filter_map(|id| map.get(id)).sum::<usize>()
+	});
+}
+
+fn bench_hashmap_u128_keys(b: &mut Bencher) {
+	let ids: Vec<u128> = (0..1000).map(|_| Ulys::new().0).collect();
+	b.iter(|| {
+		let mut map = HashMap::with_capacity(ids.len());
+		for (index, id) in ids.iter().enumerate() {
+			map.insert(*id, index);
+		}
+		ids.iter().filter_map(|id| map.get(id)).sum::<usize>()
+	});
+}
+
+fn bench_encode_base32_crate(b: &mut Bencher) {
+	let ids: Vec<Ulys> = (0..1000).map(|_| Ulys::new()).collect();
+	b.iter(|| {
+		ids.iter()
+			.map(|id| base32::encode(Alphabet::Crockford, &id.0.to_be_bytes()).to_lowercase())
+			.collect::<Vec<_>>()
+	});
+}
+
+fn bench_encode_internal(b: &mut Bencher) {
+	let ids: Vec<Ulys> = (0..1000).map(|_| Ulys::new()).collect();
+	// `join` over a single ID goes through the internal encoder only.
+	b.iter(|| {
+		ids.iter()
+			.map(|id| Ulys::join(slice::from_ref(id), ""))
+			.collect::<Vec<_>>()
+	});
+}
+
+fn bench_encode_to_string(b: &mut Bencher) {
+	let ids: Vec<Ulys> = (0..1000).map(|_| Ulys::new()).collect();
+	b.iter(|| ids.iter().map(ToString::to_string).collect::<Vec<_>>());
+}
+
+fn bench_decode_base32_crate(b: &mut Bencher) {
+	let strings: Vec<String> = (0..1000).map(|_| Ulys::new().to_string()).collect();
+	b.iter(|| {
+		strings
+			.iter()
+			.map(|s| base32::decode(Alphabet::Crockford, s))
+			.collect::<Vec<_>>()
+	});
+}
+
+fn bench_decode_from_string(b: &mut Bencher) {
+	let strings: Vec<String> = (0..1000).map(|_| Ulys::new().to_string()).collect();
+	b.iter(|| {
+		strings
+			.iter()
+			.map(|s| Ulys::from_string(s))
+			.collect::<Vec<_>>()
+	});
+}
+
+benchmark_group!(
+	ulys_perf,
+	bench_new,
+	bench_new_fast,
+	bench_new_clock,
+	bench_new_rng,
+	bench_new_rng_two_draws,
+	bench_generate,
+	bench_generate_with_rng,
+	bench_new_checksum,
+	bench_join,
+	bench_join_to_string,
+	bench_hashmap_ulys_keys,
+	bench_hashmap_u128_keys,
+	bench_encode_base32_crate,
+	bench_encode_internal,
+	bench_encode_to_string,
+	bench_decode_base32_crate,
+	bench_decode_from_string
+);
+#[cfg(feature = "simd")]
+fn bench_validate_scalar(b: &mut Bencher) {
+	let strings: Vec<String> = (0..1000).map(|_| Ulys::new().to_string()).collect();
+	b.iter(|| strings.iter().filter(|s| Ulys::decode(s).is_ok()).count());
+}
+
+#[cfg(feature = "simd")]
+fn bench_validate_simd(b: &mut Bencher) {
+	let strings: Vec<String> = (0..1000).map(|_| Ulys::new().to_string()).collect();
+	b.iter(|| {
+		strings
+			.iter()
+			.filter(|s| Ulys::is_valid_crockford(s))
+			.count()
+	});
+}
+
+#[cfg(feature = "simd")]
+fn bench_decode_scalar(b: &mut Bencher) {
+	let strings: Vec<String> = (0..1000).map(|_| Ulys::new().to_string()).collect();
+	b.iter(|| strings.iter().filter_map(|s| Ulys::decode(s).ok()).count());
+}
+
+#[cfg(feature = "simd")]
+fn bench_decode_simd(b: &mut Bencher) {
+	// Only vectorized on CPUs with AVX2 or NEON.
+	let strings: Vec<String> = (0..1000).map(|_| Ulys::new().to_string()).collect();
+	b.iter(|| {
+		strings
+			.iter()
+			.filter_map(|s| Ulys::from_string(s).ok())
+			.count()
+	});
+}
+
+#[cfg(feature = "simd")]
+benchmark_group!(
+	ulys_simd,
+	bench_validate_scalar,
+	bench_validate_simd,
+	bench_decode_scalar,
+	bench_decode_simd
+);
+
+// Contended generation: each iteration runs `THREADS` threads generating
+// `PER_THREAD` IDs each, from one shared generator or from one generator per
+// thread. Spawning the threads costs the same in every variant.
+//
+// A shared generator keeps all IDs ordered across threads, at the price of
+// every thread competing for the same state: the lock-free `AtomicGenerator`
+// retries its compare-and-swap when another thread got in first, and the
+// `Mutex` behind `Ulys::new_global_monotonic` makes threads wait in line.
+// Per-thread generators never interact, so they scale with the cores, but
+// their IDs are only ordered within each thread.
+//
+// Measured on a single core, where threads rarely overlap so contention is
+// at its lowest: 4.2 ms for the atomic generator, 4.7 ms for the mutex and
+// 3.5 ms for per-thread generators, per 40,000 IDs. The shared generators
+// cost 20% to 35% more even then, and fall further behind with each core
+// that makes the threads actually race.
+
+#[cfg(feature = "atomic")]
+const THREADS: usize = 4;
+#[cfg(feature = "atomic")]
+const PER_THREAD: usize = 10_000;
+
+#[cfg(feature = "atomic")]
+fn bench_contended_atomic(b: &mut Bencher) {
+	let generator = ulys::AtomicGenerator::new();
+	b.iter(|| {
+		std::thread::scope(|scope| {
+			for _ in 0..THREADS {
+				scope.spawn(|| {
+					for _ in 0..PER_THREAD {
+						black_box(generator.generate().unwrap());
+					}
+				});
+			}
+		});
+	});
+}
+
+#[cfg(feature = "atomic")]
+fn bench_contended_mutex(b: &mut Bencher) {
+	b.iter(|| {
+		std::thread::scope(|scope| {
+			for _ in 0..THREADS {
+				scope.spawn(|| {
+					for _ in 0..PER_THREAD {
+						black_box(Ulys::new_global_monotonic());
+					}
+				});
+			}
+		});
+	});
+}
+
+#[cfg(feature = "atomic")]
+fn bench_contended_per_thread(b: &mut Bencher) {
+	b.iter(|| {
+		std::thread::scope(|scope| {
+			for _ in 0..THREADS {
+				scope.spawn(|| {
+					let mut generator = Generator::new();
+					for _ in 0..PER_THREAD {
+						black_box(generator.generate().unwrap());
+					}
+				});
+			}
+		});
+	});
+}
+
+#[cfg(feature = "atomic")]
+benchmark_group!(
+	ulys_contended,
+	bench_contended_atomic,
+	bench_contended_mutex,
+	bench_contended_per_thread
+);
+
+#[cfg(all(feature = "simd", feature = "atomic"))]
+benchmark_main!(ulys_perf, ulys_simd, ulys_contended);
+#[cfg(all(feature = "simd", not(feature = "atomic")))]
+benchmark_main!(ulys_perf, ulys_simd);
+#[cfg(all(not(feature = "simd"), feature = "atomic"))]
+benchmark_main!(ulys_perf, ulys_contended);
+#[cfg(not(any(feature = "simd", feature = "atomic")))]
+benchmark_main!(ulys_perf);
@@ -0,0 +1,72 @@
+//! Extraction of ULYSes from `actix-web` requests.
+
+use crate::Ulys;
+use actix_web::dev::Payload;
+use actix_web::error::ErrorBadRequest;
+use actix_web::{Error, FromRequest, HttpRequest};
+use std::future::{ready, Ready};
+
+/// Extracts a Ulys from the single path parameter of a route.
+///
+/// Routes with several parameters should extract `web::Path<(..., Ulys, ...)>`
+/// with the `serde` feature instead. A parameter that is not a valid Ulys is
+/// rejected with a `400 Bad Request`.
+///
+/// # Examples
+/// ```
+/// # use actix_web::{web, App};
+/// # use ulys::Ulys;
+/// async fn show_order(id: Ulys) -> String {
+///     id.to_string()
+/// }
+///
+/// let app = App::new().route("/orders/{id}", web::get().to(show_order));
+/// ```
+impl FromRequest for Ulys {
+	type Error = Error;
+	type Future = Ready<Result<Self, Error>>;
+
+	fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+		let mut segments = req.match_info().iter();
+
+		let result = match (segments.next(), segments.next()) {
+			(Some((_, segment)), None) => Ulys::from_string(segment)
+				.map_err(|err| ErrorBadRequest(format!("invalid ulys: {err}"))),
+			_ => Err(ErrorBadRequest("expected a single path parameter")),
+		};
+
+		ready(result)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Ulys;
+	use actix_web::http::StatusCode;
+	use actix_web::{test, web, App};
+
+	#[actix_web::test]
+	async fn actix_extractor() {
+		let app = test::init_service(App::new().route(
+			"/orders/{id}",
+			web::get().to(|id: Ulys| async move { id.to_string() }),
+		))
+		.await;
+
+		let valid = test::TestRequest::get()
+			.uri(&format!("/orders/{}", Ulys::new()))
+			.to_request();
+		assert_eq!(
+			test::call_service(&app, valid).await.status(),
+			StatusCode::OK
+		);
+
+		let invalid = test::TestRequest::get()
+			.uri("/orders/not-a-ulys")
+			.to_request();
+		assert_eq!(
+			test::call_service(&app, invalid).await.status(),
+			StatusCode::BAD_REQUEST
+		);
+	}
+}
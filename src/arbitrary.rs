@@ -0,0 +1,49 @@
+//! Generation of ULYSes from fuzzer input.
+
+use crate::Ulys;
+use arbitrary::{size_hint, Arbitrary, Unstructured};
+
+/// Builds a valid Ulys from arbitrary timestamp and random portions.
+///
+/// Only the checksum is computed rather than drawn, so fuzz targets exercise
+/// the whole timestamp and random range with IDs that pass
+/// [`Ulys::is_valid`].
+impl<'a> Arbitrary<'a> for Ulys {
+	fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+		let timestamp_ms = u64::arbitrary(u)?;
+		let random = u64::arbitrary(u)?;
+
+		Ok(Ulys::from_parts(timestamp_ms, random))
+	}
+
+	fn size_hint(depth: usize) -> (usize, Option<usize>) {
+		size_hint::and(u64::size_hint(depth), u64::size_hint(depth))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn arbitrary_is_valid() {
+		let data: Vec<u8> = (0..=255).cycle().take(16 * 64).collect();
+		let mut u = Unstructured::new(&data);
+
+		for _ in 0..64 {
+			let ulys = Ulys::arbitrary(&mut u).unwrap();
+			assert!(ulys.is_valid());
+		}
+		assert!(u.is_empty());
+	}
+
+	#[test]
+	fn arbitrary_reads_timestamp_and_random() {
+		let mut data = [0; 16];
+		data[..8].copy_from_slice(&1_700_000_000_000u64.to_le_bytes());
+		data[8..].copy_from_slice(&0x1234_5678_9abcu64.to_le_bytes());
+
+		let ulys = Ulys::arbitrary(&mut Unstructured::new(&data)).unwrap();
+		assert_eq!(ulys, Ulys::from_parts(1_700_000_000_000, 0x1234_5678_9abc));
+	}
+}
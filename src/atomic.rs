@@ -0,0 +1,168 @@
+//! Lock-free monotonic generation of ULYSes.
+
+use crate::{time_utils, MonotonicError, Ulys};
+use portable_atomic::{AtomicU128, Ordering};
+use std::time::SystemTime;
+
+/// A monotonic Ulys generator that can be shared across threads without a
+/// lock.
+///
+/// It follows the rules of [`Generator`](crate::Generator), but the previous
+/// Ulys is stored in an atomic and updated with a compare-and-swap loop, so
+/// `generate` takes `&self` and threads calling it concurrently never wait on
+/// each other, they only retry when another thread got in first. Every Ulys
+/// is greater than all those previously returned to any thread.
+///
+/// On targets without a native 128-bit compare-and-swap, `portable-atomic`
+/// falls back to a lock internally.
+///
+/// Sharing one generator still has a cost: threads contend on the same
+/// cache line and retry their updates, so throughput drops as threads are
+/// added. When IDs only need to be ordered within each thread, one
+/// [`Generator`](crate::Generator) per thread scales better. The
+/// `bench_contended_*` benchmarks compare both.
+///
+/// # Examples
+/// ```
+/// # use ulys::AtomicGenerator;
+/// static GENERATOR: AtomicGenerator = AtomicGenerator::new();
+///
+/// let ulys1 = GENERATOR.generate().unwrap();
+/// let ulys2 = std::thread::spawn(|| GENERATOR.generate().unwrap()).join().unwrap();
+///
+/// assert!(ulys1 < ulys2);
+/// ```
+#[derive(Debug, Default)]
+pub struct AtomicGenerator {
+	previous: AtomicU128,
+}
+
+impl AtomicGenerator {
+	/// Creates a new generator
+	#[must_use]
+	pub const fn new() -> AtomicGenerator {
+		AtomicGenerator {
+			previous: AtomicU128::new(Ulys::NIL.0),
+		}
+	}
+
+	/// Generates a new Ulys that is greater than the previous one, using the
+	/// current time (UTC)
+	///
+	/// # Errors
+	///
+	/// A `MonotonicError::Overflow` will be returned when the random portion
+	/// cannot be incremented anymore within the current millisecond.
+	pub fn generate(&self) -> Result<Ulys, MonotonicError> {
+		self.generate_from_datetime(time_utils::now())
+	}
+
+	/// Generates a new Ulys that is greater than the previous one, using the
+	/// given datetime
+	///
+	/// A datetime earlier than the previous Ulys is treated as the same
+	/// millisecond, so a clock going backwards does not break the ordering.
+	///
+	/// # Errors
+	///
+	/// A `MonotonicError::Overflow` will be returned when the random portion
+	/// cannot be incremented anymore within the given millisecond.
+	pub fn generate_from_datetime(&self, datetime: SystemTime) -> Result<Ulys, MonotonicError> {
+		let timestamp = Ulys::unix_millis(datetime);
+		let source = &mut rand::rng();
+		let mut previous = Ulys(self.previous.load(Ordering::Acquire));
+
+		loop {
+			let next = if timestamp <= previous.timestamp_ms() {
+				previous.increment().ok_or(MonotonicError::Overflow)?
+			} else {
+				Ulys::from_timestamp_with_source(timestamp, source)
+			};
+
+			match self.previous.compare_exchange_weak(
+				previous.0,
+				next.0,
+				Ordering::AcqRel,
+				Ordering::Acquire,
+			) {
+				Ok(_) => return Ok(next),
+				Err(current) => previous = Ulys(current),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::collections::HashSet;
+	use std::sync::Arc;
+	use std::thread;
+	use std::time::Duration;
+
+	#[test]
+	fn atomic_generator_order() {
+		let dt = SystemTime::now();
+		let generator = AtomicGenerator::new();
+
+		let ulys1 = generator.generate_from_datetime(dt).unwrap();
+		let ulys2 = generator.generate_from_datetime(dt).unwrap();
+		let ulys3 = generator
+			.generate_from_datetime(dt - Duration::from_millis(1))
+			.unwrap();
+		let ulys4 = generator
+			.generate_from_datetime(dt + Duration::from_millis(1))
+			.unwrap();
+
+		assert!(ulys1 < ulys2);
+		assert!(ulys2 < ulys3);
+		assert!(ulys3 < ulys4);
+		assert_eq!(ulys2.random(), ulys1.random() + 1);
+		assert!([ulys1, ulys2, ulys3, ulys4].iter().all(Ulys::is_valid));
+	}
+
+	#[test]
+	fn atomic_generator_overflow() {
+		let dt = SystemTime::now();
+		let generator = AtomicGenerator::new();
+		generator.previous.store(
+			Ulys::from_parts(Ulys::unix_millis(dt), Ulys::RAND_MAX).0,
+			Ordering::Relaxed,
+		);
+
+		assert_eq!(
+			generator.generate_from_datetime(dt),
+			Err(MonotonicError::Overflow)
+		);
+		assert!(generator
+			.generate_from_datetime(dt + Duration::from_millis(1))
+			.is_ok());
+	}
+
+	#[test]
+	fn atomic_generator_threads() {
+		let generator = Arc::new(AtomicGenerator::new());
+
+		let handles: Vec<_> = (0..8)
+			.map(|_| {
+				let generator = Arc::clone(&generator);
+				thread::spawn(move || {
+					(0..10_000)
+						.map(|_| generator.generate().unwrap())
+						.collect::<Vec<_>>()
+				})
+			})
+			.collect();
+		let batches: Vec<Vec<Ulys>> = handles
+			.into_iter()
+			.map(|handle| handle.join().unwrap())
+			.collect();
+
+		let mut seen = HashSet::new();
+		for batch in &batches {
+			assert!(batch.windows(2).all(|pair| pair[0] < pair[1]));
+			assert!(batch.iter().all(|ulys| seen.insert(*ulys)));
+		}
+		assert_eq!(seen.len(), 80_000);
+	}
+}
@@ -0,0 +1,68 @@
+//! Extraction of ULYSes from `axum` requests.
+
+use crate::Ulys;
+use axum::extract::{FromRequestParts, Path};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+
+/// Extracts a Ulys from the single path parameter of a route.
+///
+/// Routes with several parameters should extract `Path<(..., Ulys, ...)>`
+/// with the `serde` feature instead. A parameter that is not a valid Ulys is
+/// rejected with a `400 Bad Request`.
+///
+/// # Examples
+/// ```
+/// # use axum::{routing::get, Router};
+/// # use ulys::Ulys;
+/// async fn show_order(id: Ulys) -> String {
+///     id.to_string()
+/// }
+///
+/// let app: Router = Router::new().route("/orders/{id}", get(show_order));
+/// ```
+impl<S> FromRequestParts<S> for Ulys
+where
+	S: Send + Sync,
+{
+	type Rejection = Response;
+
+	async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+		let Path(segment) = Path::<String>::from_request_parts(parts, state)
+			.await
+			.map_err(IntoResponse::into_response)?;
+
+		Ulys::from_string(&segment).map_err(|err| {
+			(StatusCode::BAD_REQUEST, format!("invalid ulys: {err}")).into_response()
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::Ulys;
+	use axum::body::Body;
+	use axum::http::{Request, StatusCode};
+	use axum::routing::get;
+	use axum::Router;
+	use tower::ServiceExt;
+
+	async fn status(uri: &str) -> StatusCode {
+		let app = Router::new().route(
+			"/orders/{id}",
+			get(|id: Ulys| async move { id.to_string() }),
+		);
+		let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+		app.oneshot(request).await.unwrap().status()
+	}
+
+	#[tokio::test]
+	async fn axum_extractor() {
+		let valid = format!("/orders/{}", Ulys::new());
+
+		assert_eq!(status(&valid).await, StatusCode::OK);
+		assert_eq!(status("/orders/not-a-ulys").await, StatusCode::BAD_REQUEST);
+	}
+}
@@ -0,0 +1,154 @@
+//! Crockford Base32 encoding and decoding of ULYSes.
+//!
+//! The 128 bits are read 5 at a time from the most significant end, so the
+//! 26th character holds the last 3 bits followed by 2 zero padding bits.
+
+use crate::{Ulys, UlysError};
+
+/// The Crockford Base32 alphabet, in the lowercase form used by `Display`
+const ALPHABET: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+/// Marks the bytes of `LOOKUP` that are not part of the alphabet
+const NO_VALUE: u8 = u8::MAX;
+
+/// The value of each byte of the alphabet, in either case
+const LOOKUP: [u8; 256] = {
+	let mut lookup = [NO_VALUE; 256];
+	let mut i: u8 = 0;
+
+	while (i as usize) < ALPHABET.len() {
+		let symbol = ALPHABET[i as usize];
+		lookup[symbol as usize] = i;
+		lookup[symbol.to_ascii_uppercase() as usize] = i;
+		i += 1;
+	}
+
+	lookup
+};
+
+/// Error while decoding a Crockford Base32 string
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum DecodeError {
+	/// The string is not 26 bytes long
+	InvalidLength,
+	/// The byte at `index` is not part of the alphabet
+	InvalidCharAt { index: usize, byte: u8 },
+}
+
+impl DecodeError {
+	/// Converts this error into a `UlysError`, in const contexts
+	pub(crate) const fn into_ulys_error(self) -> UlysError {
+		match self {
+			DecodeError::InvalidLength => UlysError::ParseInvalidLength,
+			DecodeError::InvalidCharAt { index, byte } => UlysError::InvalidCharAt { index, byte },
+		}
+	}
+}
+
+impl From<DecodeError> for UlysError {
+	fn from(err: DecodeError) -> Self {
+		err.into_ulys_error()
+	}
+}
+
+/// Encodes a value as lowercase Crockford Base32 ASCII bytes
+pub(crate) const fn encode(value: u128) -> [u8; Ulys::ULYS_LEN] {
+	let mut encoded = [0; Ulys::ULYS_LEN];
+	let mut i = 0;
+
+	while i < Ulys::ULYS_LEN - 1 {
+		encoded[i] = ALPHABET[((value >> (123 - 5 * i)) & 0x1f) as usize];
+		i += 1;
+	}
+	encoded[Ulys::ULYS_LEN - 1] = ALPHABET[((value & 0x07) << 2) as usize];
+
+	encoded
+}
+
+/// Decodes Crockford Base32 ASCII bytes, in either case
+///
+/// Only the 32 symbols of the alphabet are accepted: the ambiguous letters
+/// `I`, `L` and `O` and the letter `U` are rejected. The 2 padding bits of
+/// the last character are ignored.
+pub(crate) const fn decode(bytes: &[u8]) -> Result<u128, DecodeError> {
+	if bytes.len() != Ulys::ULYS_LEN {
+		return Err(DecodeError::InvalidLength);
+	}
+
+	let mut value: u128 = 0;
+	let mut i = 0;
+
+	while i < Ulys::ULYS_LEN {
+		let digit = LOOKUP[bytes[i] as usize];
+		if digit == NO_VALUE {
+			return Err(DecodeError::InvalidCharAt {
+				index: i,
+				byte: bytes[i],
+			});
+		}
+
+		value = if i < Ulys::ULYS_LEN - 1 {
+			value << 5 | digit as u128
+		} else {
+			value << 3 | (digit >> 2) as u128
+		};
+		i += 1;
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_round_trip() {
+		for value in [0, 1, u128::MAX, 0x0123_4567_89ab_cdef_0123_4567_89ab_cdef] {
+			let encoded = encode(value);
+			let text = core::str::from_utf8(&encoded).unwrap();
+
+			assert_eq!(decode(&encoded), Ok(value));
+			assert_eq!(decode(text.to_uppercase().as_bytes()), Ok(value));
+		}
+	}
+
+	#[test]
+	fn test_matches_base32_crate() {
+		for _ in 0..1000 {
+			let value: u128 = rand::random();
+			let expected = ::base32::encode(::base32::Alphabet::Crockford, &value.to_be_bytes());
+
+			assert_eq!(encode(value), expected.to_lowercase().as_bytes());
+			assert_eq!(decode(expected.as_bytes()), Ok(value));
+		}
+	}
+
+	#[test]
+	fn test_decode_invalid_char_at() {
+		// cSpell:disable
+		assert_eq!(
+			decode(b"068cbxpc1wy9d0v9gbhrgu020r"),
+			Err(DecodeError::InvalidCharAt {
+				index: 21,
+				byte: b'u'
+			})
+		);
+		assert_eq!(
+			decode(b"L68cbxpc1wy9d0v9gbhrg0020r"),
+			Err(DecodeError::InvalidCharAt {
+				index: 0,
+				byte: b'L'
+			})
+		);
+		assert_eq!(
+			decode("068cbxpc1wy9d0v9gbhrg002é".as_bytes()),
+			Err(DecodeError::InvalidCharAt {
+				index: 24,
+				byte: 0xc3
+			})
+		);
+		// cSpell:enable
+		assert_eq!(decode(b"068cbxpc"), Err(DecodeError::InvalidLength));
+	}
+}
@@ -0,0 +1,129 @@
+//! Base62 encoding and decoding of ULYSes.
+//!
+//! The 128 bits are written as a 22-digit base62 number, most significant
+//! digit first and zero-padded, with the digits `0-9`, `A-Z` and `a-z`.
+
+use crate::{Ulys, UlysError};
+use alloc::string::String;
+
+/// The base62 alphabet, in ASCII order
+const ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Length of a base62-encoded Ulys: 62^22 is the first power of 62 above
+/// 2^128
+const BASE62_LEN: usize = 22;
+
+impl Ulys {
+	/// Encodes this Ulys as 22 base62 characters (`0-9A-Za-z`)
+	///
+	/// This is the most compact URL-safe form, 4 characters shorter than the
+	/// Crockford Base32 string, with two caveats:
+	/// - encoding and decoding divide and multiply 128-bit integers digit by
+	///   digit, which is several times slower than the bit shifts of base32;
+	/// - the strings only sort like the IDs when compared byte by byte, as
+	///   their digits are in ASCII order and they are zero-padded to a fixed
+	///   length. Case-insensitive collations, common in databases, mix up
+	///   `A` and `a` and break the order, unlike the single-case base32.
+	#[must_use]
+	pub fn to_base62(&self) -> String {
+		let mut digits = [ALPHABET[0]; BASE62_LEN];
+		let mut value = self.0;
+
+		for digit in digits.iter_mut().rev() {
+			// The remainder is below 62.
+			#[allow(clippy::cast_possible_truncation)]
+			let index = (value % 62) as usize;
+			*digit = ALPHABET[index];
+			value /= 62;
+		}
+
+		digits.iter().copied().map(char::from).collect()
+	}
+
+	/// Creates a Ulys from the 22 base62 characters written by
+	/// [`Ulys::to_base62`]
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the string is not 22 characters
+	/// long, with `UlysError::InvalidCharAt` for a character outside of the
+	/// alphabet, or `UlysError::ValueOverflow` when the number does not
+	/// fit in 128 bits.
+	pub fn from_base62(s: &str) -> Result<Ulys, UlysError> {
+		if s.len() != BASE62_LEN {
+			return Err(UlysError::ParseInvalidLength);
+		}
+
+		s.bytes()
+			.enumerate()
+			.try_fold(0u128, |value, (index, byte)| {
+				let digit = match byte {
+					b'0'..=b'9' => byte - b'0',
+					b'A'..=b'Z' => byte - b'A' + 10,
+					b'a'..=b'z' => byte - b'a' + 36,
+					_ => return Err(UlysError::InvalidCharAt { index, byte }),
+				};
+
+				value
+					.checked_mul(62)
+					.and_then(|value| value.checked_add(u128::from(digit)))
+					.ok_or(UlysError::ValueOverflow)
+			})
+			.map(Ulys)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn base62_cycle() {
+		for ulys in [
+			Ulys::NIL,
+			Ulys::MAX,
+			Ulys(1),
+			Ulys(61),
+			Ulys(62),
+			Ulys::new(),
+		] {
+			let encoded = ulys.to_base62();
+
+			assert_eq!(encoded.len(), 22);
+			assert_eq!(Ulys::from_base62(&encoded), Ok(ulys));
+		}
+
+		assert_eq!(Ulys::NIL.to_base62(), "0000000000000000000000");
+		assert_eq!(Ulys::MAX.to_base62(), "7n42DGM5Tflk9n8mt7Fhc7");
+		assert_eq!(Ulys(61).to_base62(), "000000000000000000000z");
+		assert_eq!(Ulys(62).to_base62(), "0000000000000000000010");
+	}
+
+	#[test]
+	fn base62_sorts_bytewise() {
+		let mut ids: Vec<Ulys> = (0..1000).map(|_| Ulys(rand::random())).collect();
+		let mut encoded: Vec<String> = ids.iter().map(Ulys::to_base62).collect();
+
+		ids.sort_unstable();
+		encoded.sort_unstable();
+
+		assert_eq!(encoded, ids.iter().map(Ulys::to_base62).collect::<Vec<_>>());
+	}
+
+	#[test]
+	fn base62_rejects_invalid_input() {
+		assert_eq!(Ulys::from_base62("000"), Err(UlysError::ParseInvalidLength));
+		assert_eq!(
+			Ulys::from_base62("00000000000-0000000000"),
+			Err(UlysError::InvalidCharAt {
+				index: 11,
+				byte: b'-'
+			})
+		);
+		// One above `u128::MAX`.
+		assert_eq!(
+			Ulys::from_base62("7n42DGM5Tflk9n8mt7Fhc8"),
+			Err(UlysError::ValueOverflow)
+		);
+	}
+}
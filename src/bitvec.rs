@@ -0,0 +1,47 @@
+//! Bulk validation of ULYSes into bit vectors.
+
+use crate::Ulys;
+use bitvec::vec::BitVec;
+
+impl Ulys {
+	/// Checks every Ulys of a batch, returning one bit per ID that is set when
+	/// it passes [`Ulys::is_valid`]
+	///
+	/// A bit takes an eighth of the memory of a `bool`, which matters when
+	/// auditing millions of IDs. The number of invalid IDs is the number of
+	/// unset bits, `bits.count_zeros()`, also given by
+	/// [`Ulys::count_invalid`] without allocating.
+	#[must_use]
+	pub fn validate_all_bits(ids: &[Ulys]) -> BitVec {
+		ids.iter().map(Ulys::is_valid).collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn validate_all_bits_matches_is_valid() {
+		let ids: Vec<Ulys> = (0..100u64)
+			.map(|index| {
+				let ulys = Ulys::from_parts(1_700_000_000_000 + index, index);
+				if index % 3 == 0 {
+					Ulys(ulys.0 ^ 1)
+				} else {
+					ulys
+				}
+			})
+			.collect();
+
+		let bits = Ulys::validate_all_bits(&ids);
+
+		assert_eq!(bits.len(), ids.len());
+		for (bit, ulys) in bits.iter().zip(&ids) {
+			assert_eq!(*bit, ulys.is_valid());
+		}
+		assert_eq!(bits.count_zeros(), 34);
+		assert_eq!(bits.count_zeros(), Ulys::count_invalid(&ids));
+		assert!(Ulys::validate_all_bits(&[]).is_empty());
+	}
+}
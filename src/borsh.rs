@@ -0,0 +1,71 @@
+//! Borsh serialization of ULYSes.
+//!
+//! A Ulys is written as its 16 big-endian bytes, see [`Ulys::to_bytes`], so
+//! serialized keys compare byte by byte in the same order as the IDs and as
+//! their strings.
+//!
+//! Deserialization reads raw bytes and does not check the checksum, like
+//! [`Ulys::from_bytes`]: call [`Ulys::is_valid`] or [`Ulys::verify`] on the
+//! result when the records may be corrupted.
+
+use crate::Ulys;
+use borsh::io::{Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+impl BorshSerialize for Ulys {
+	fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+		self.to_bytes().serialize(writer)
+	}
+}
+
+impl BorshDeserialize for Ulys {
+	fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+		<[u8; 16]>::deserialize_reader(reader).map(Ulys::from_bytes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn borsh_cycle() {
+		for ulys in [Ulys::new(), Ulys::NIL, Ulys::MAX] {
+			let bytes = borsh::to_vec(&ulys).unwrap();
+
+			assert_eq!(bytes, ulys.to_bytes());
+			assert_eq!(borsh::from_slice::<Ulys>(&bytes).unwrap(), ulys);
+		}
+	}
+
+	#[test]
+	fn borsh_keeps_invalid_checksums() {
+		let ulys = borsh::from_slice::<Ulys>(&Ulys::MAX.to_bytes()).unwrap();
+
+		assert_eq!(ulys, Ulys::MAX);
+		assert!(ulys.verify().is_err());
+	}
+
+	#[test]
+	fn borsh_preserves_order() {
+		let mut ids: Vec<Ulys> = (0..100).map(|_| Ulys::new_fast()).collect();
+		let mut records: Vec<Vec<u8>> = ids
+			.iter()
+			.map(|ulys| borsh::to_vec(ulys).unwrap())
+			.collect();
+
+		ids.sort_unstable();
+		records.sort_unstable();
+
+		let sorted: Vec<Ulys> = records
+			.iter()
+			.map(|record| borsh::from_slice(record).unwrap())
+			.collect();
+		assert_eq!(sorted, ids);
+	}
+
+	#[test]
+	fn borsh_rejects_short_input() {
+		assert!(borsh::from_slice::<Ulys>(&[0; 15]).is_err());
+	}
+}
@@ -0,0 +1,45 @@
+//! Zero-copy byte views of ULYSes.
+//!
+//! `Ulys` is a transparent wrapper around a `u128`, so a `&[Ulys]` can be
+//! reinterpreted as `&[[u8; 16]]` with [`bytemuck::cast_slice`], e.g. for a
+//! memory-mapped index.
+//!
+//! The bytes are the in-memory representation of the `u128`, in the native
+//! endianness, not the canonical big-endian order of [`Ulys::to_bytes`]: on
+//! little-endian machines they do not sort like the IDs, and files written on
+//! one architecture do not read back on one of the other endianness.
+
+use crate::Ulys;
+use bytemuck::{Pod, Zeroable};
+
+// SAFETY: `Ulys` is `repr(transparent)` over a `u128`, for which every bit
+// pattern is valid, including all zeros.
+unsafe impl Zeroable for Ulys {}
+
+// SAFETY: `Ulys` is `repr(transparent)` over a `u128`, which is `Pod`: it is
+// `Copy`, has no padding and every bit pattern is valid.
+unsafe impl Pod for Ulys {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn cast_slice_to_bytes() {
+		let ids = [Ulys::new(), Ulys::NIL, Ulys::MAX];
+		let bytes: &[[u8; 16]] = bytemuck::cast_slice(&ids);
+
+		assert_eq!(bytes.len(), ids.len());
+		for (bytes, ulys) in bytes.iter().zip(&ids) {
+			assert_eq!(*bytes, ulys.0.to_ne_bytes());
+		}
+
+		let back: &[Ulys] = bytemuck::cast_slice(bytes);
+		assert_eq!(back, ids);
+	}
+
+	#[test]
+	fn zeroed_is_nil() {
+		assert_eq!(Ulys::zeroed(), Ulys::NIL);
+	}
+}
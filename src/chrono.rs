@@ -0,0 +1,85 @@
+//! Conversions between ULYS and `chrono` datetimes.
+
+use crate::Ulys;
+use chrono::{DateTime, Utc};
+
+impl Ulys {
+	/// Creates a new Ulys with the given `chrono` datetime
+	///
+	/// The datetime is truncated to the millisecond and clamped like
+	/// `SystemTime` datetimes: datetimes before the Unix epoch are stored as
+	/// the epoch itself, and datetimes past the 48-bit millisecond range are
+	/// stored as the largest timestamp.
+	#[must_use]
+	pub fn from_chrono(datetime: DateTime<Utc>) -> Ulys {
+		let millis = u64::try_from(datetime.timestamp_millis()).unwrap_or(0);
+
+		Ulys::from_timestamp(millis.min(Ulys::TIME_MAX))
+	}
+
+	/// Gets the `chrono` datetime of when this Ulys was created accurate to
+	/// 1ms
+	#[must_use]
+	pub fn to_chrono(&self) -> DateTime<Utc> {
+		// Any 48-bit timestamp is within the range of `DateTime`.
+		let millis = i64::try_from(self.timestamp_ms()).unwrap_or(i64::MAX);
+
+		DateTime::from_timestamp_millis(millis).unwrap_or(DateTime::<Utc>::MAX_UTC)
+	}
+}
+
+impl From<DateTime<Utc>> for Ulys {
+	fn from(datetime: DateTime<Utc>) -> Self {
+		Ulys::from_chrono(datetime)
+	}
+}
+
+impl From<Ulys> for DateTime<Utc> {
+	fn from(ulys: Ulys) -> Self {
+		ulys.to_chrono()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::TimeDelta;
+
+	#[test]
+	fn chrono_cycle() {
+		let datetime = DateTime::from_timestamp_millis(1_700_000_000_123).unwrap();
+		let ulys = Ulys::from_chrono(datetime);
+
+		assert_eq!(ulys.timestamp_ms(), 1_700_000_000_123);
+		assert_eq!(ulys.to_chrono(), datetime);
+		assert_eq!(DateTime::<Utc>::from(ulys), datetime);
+		assert_eq!(
+			ulys.to_chrono().timestamp_millis(),
+			Ulys::from_datetime(ulys.datetime())
+				.to_chrono()
+				.timestamp_millis()
+		);
+		assert!(Ulys::from(datetime).is_valid());
+	}
+
+	#[test]
+	fn chrono_truncates_sub_milliseconds() {
+		let datetime = DateTime::from_timestamp(1_700_000_000, 123_999_999).unwrap();
+		let ulys = Ulys::from_chrono(datetime);
+
+		assert_eq!(ulys.timestamp_ms(), 1_700_000_000_123);
+		assert_eq!(ulys.to_chrono(), datetime - TimeDelta::nanoseconds(999_999));
+	}
+
+	#[test]
+	fn chrono_clamps_to_range() {
+		let before_epoch = DateTime::UNIX_EPOCH - TimeDelta::days(1);
+		assert_eq!(
+			Ulys::from_chrono(before_epoch).to_chrono(),
+			DateTime::UNIX_EPOCH
+		);
+
+		let far_future = DateTime::from_timestamp(i64::from(i32::MAX) * 1000, 0).unwrap();
+		assert_eq!(Ulys::from_chrono(far_future).timestamp_ms(), Ulys::TIME_MAX);
+	}
+}
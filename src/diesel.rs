@@ -0,0 +1,104 @@
+//! Conversions between ULYS and Postgres `uuid` values through `diesel`.
+//!
+//! ULYSes are stored as the UUID with the same bits, so a `Ulys` field maps
+//! to a column of the `Uuid` SQL type without any attribute.
+//!
+//! # Examples
+//! ```
+//! # use diesel::prelude::*;
+//! # use ulys::Ulys;
+//! diesel::table! {
+//!     events (id) {
+//!         id -> Uuid,
+//!     }
+//! }
+//!
+//! #[derive(Queryable, Insertable)]
+//! #[diesel(table_name = events)]
+//! struct Event {
+//!     id: Ulys,
+//! }
+//!
+//! let query = events::table.filter(events::id.eq(Ulys::new()));
+//! ```
+
+use crate::Ulys;
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::Uuid;
+use std::io::Write;
+
+/// Derives `AsExpression` and `FromSqlRow` for `Ulys` from outside its
+/// definition.
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Uuid)]
+#[allow(dead_code)]
+struct UlysProxy(Ulys);
+
+impl FromSql<Uuid, Pg> for Ulys {
+	fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+		uuid::Uuid::from_sql(value).map(Ulys::from)
+	}
+}
+
+impl ToSql<Uuid, Pg> for Ulys {
+	fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+		out.write_all(uuid::Uuid::from(*self).as_bytes())?;
+		Ok(IsNull::No)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use core::num::NonZeroU32;
+	use diesel::pg::{PgMetadataLookup, PgTypeMetadata};
+	use diesel::prelude::*;
+	use diesel::query_builder::bind_collector::RawBytesBindCollector;
+	use diesel::query_builder::QueryFragment;
+
+	diesel::table! {
+		events (id) {
+			id -> Uuid,
+		}
+	}
+
+	#[derive(Queryable, Insertable)]
+	#[diesel(table_name = events)]
+	struct Event {
+		id: Ulys,
+	}
+
+	/// The `uuid` type has a static OID, so no lookup ever happens.
+	struct NoLookup;
+
+	impl PgMetadataLookup for NoLookup {
+		fn lookup_type(&mut self, type_name: &str, _schema: Option<&str>) -> PgTypeMetadata {
+			unreachable!("unexpected lookup of {type_name}")
+		}
+	}
+
+	#[test]
+	fn diesel_cycle() {
+		let ulys = Ulys::new();
+		let query = diesel::insert_into(events::table).values(Event { id: ulys });
+
+		let mut collector = RawBytesBindCollector::<Pg>::new();
+		query
+			.collect_binds(
+				&mut collector,
+				&mut NoLookup as &mut dyn PgMetadataLookup,
+				&Pg,
+			)
+			.unwrap();
+		assert_eq!(collector.binds, [Some(ulys.to_bytes().to_vec())]);
+
+		let bytes = collector.binds[0].take().unwrap();
+		let oid = NonZeroU32::new(2950).unwrap();
+		assert_eq!(Ulys::from_sql(PgValue::new(&bytes, &oid)).unwrap(), ulys);
+		assert!(Ulys::from_sql(PgValue::new(&bytes[..15], &oid)).is_err());
+	}
+}
@@ -0,0 +1,694 @@
+//! Monotonic generation of ULYSes.
+
+use crate::{time_utils, Ulys};
+use core::fmt;
+use rand::{Rng, RngExt};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// A Ulys generator that provides monotonically increasing IDs.
+///
+/// When a Ulys is requested within the same millisecond as the previous one,
+/// the generator increments the random portion of the previous Ulys instead
+/// of drawing a new one, so every generated Ulys is strictly greater than the
+/// previous one.
+///
+/// # Examples
+/// ```
+/// # use ulys::Generator;
+/// let mut generator = Generator::new();
+///
+/// let ulys1 = generator.generate().unwrap();
+/// let ulys2 = generator.generate().unwrap();
+///
+/// assert!(ulys1 < ulys2);
+/// ```
+#[derive(Default)]
+pub struct Generator {
+	pub(crate) previous: Ulys,
+	scattered: bool,
+	anchor: Option<(SystemTime, Instant)>,
+	rng: Option<Box<dyn Rng + Send>>,
+	tenant: Option<u16>,
+	key: Option<[u8; 32]>,
+}
+
+impl Generator {
+	/// Creates a new generator
+	#[must_use]
+	pub const fn new() -> Generator {
+		Generator {
+			previous: Ulys::NIL,
+			scattered: false,
+			anchor: None,
+			rng: None,
+			tenant: None,
+			key: None,
+		}
+	}
+
+	/// Scatters the random portion of the IDs to come
+	///
+	/// IDs generated within the same millisecond normally only differ in the
+	/// lowest bits of their random portion, so they land next to each other
+	/// in B-tree indexes and concentrate inserts on the same pages. The
+	/// generator then bit-reverses the 48-bit random portion of the IDs it returns
+	/// so that consecutive IDs spread across the whole random range.
+	///
+	/// The timestamp ordering is preserved, so range scans on time still
+	/// work, but IDs created within the same millisecond are no longer
+	/// ordered relative to each other and ranges over the random portion are
+	/// meaningless. The IDs are still unique and carry a valid checksum.
+	#[must_use]
+	pub const fn with_scattered_random(mut self) -> Generator {
+		self.scattered = true;
+		self
+	}
+
+	/// Reads time from a monotonic clock anchored to the wall clock
+	///
+	/// `SystemTime` can jump backwards, for instance on NTP adjustments, which
+	/// [`Generator::generate`] otherwise papers over by incrementing the
+	/// previous Ulys for as long as the clock is behind. The generator
+	/// then takes the current time as `wall` plus the time elapsed since
+	/// `mono`, so its timestamps never go backwards. The wall clock is read
+	/// only once, by the caller, and any drift between the two clocks
+	/// accumulates from then on.
+	#[must_use]
+	pub const fn with_monotonic_anchor(mut self, wall: SystemTime, mono: Instant) -> Generator {
+		self.anchor = Some((wall, mono));
+		self
+	}
+
+	/// Draws the random portions of the IDs to come from `rng`
+	///
+	/// Without it, the generator looks up the thread-local RNG on every draw.
+	/// With it, the generator owns `rng` and reuses it, which saves that lookup on hot paths when
+	/// given a fast RNG such as `SmallRng`, and makes the generated IDs
+	/// reproducible for a given clock when given a seeded one. The explicit
+	/// source of [`Generator::generate_with_source`] still takes precedence.
+	///
+	/// Like the other options, this can be combined with the rest, for
+	/// instance with [`Generator::with_monotonic_anchor`].
+	///
+	/// # Examples
+	/// ```
+	/// # use rand::{rngs::StdRng, SeedableRng};
+	/// # use std::time::SystemTime;
+	/// # use ulys::Generator;
+	/// let dt = SystemTime::now();
+	/// let mut generator1 = Generator::new().with_rng(StdRng::seed_from_u64(42));
+	/// let mut generator2 = Generator::new().with_rng(StdRng::seed_from_u64(42));
+	///
+	/// assert_eq!(
+	///     generator1.generate_from_datetime(dt),
+	///     generator2.generate_from_datetime(dt)
+	/// );
+	/// ```
+	#[must_use]
+	pub fn with_rng<R>(mut self, rng: R) -> Generator
+	where
+		R: Rng + Send + 'static,
+	{
+		self.rng = Some(Box::new(rng));
+		self
+	}
+
+	/// Advances the generator to the current time without issuing a Ulys
+	///
+	/// A generator whose last Ulys is older than the current millisecond, for
+	/// instance one restored after downtime, is moved to the current
+	/// millisecond so that the next generated Ulys reflects the current time
+	/// from a known state. The stored random portion is drawn fresh rather
+	/// than set to zero, so generators warmed up in the same millisecond in
+	/// different processes do not issue the same IDs. A generator that is
+	/// already at or past the current time is left untouched.
+	pub fn warmup(&mut self) {
+		let timestamp = Ulys::unix_millis(self.now());
+
+		if timestamp > self.previous.timestamp_ms() {
+			self.previous = match self.rng.as_deref_mut() {
+				Some(rng) => Self::draw(self.tenant, timestamp, rng),
+				None => Self::draw(self.tenant, timestamp, &mut rand::rng()),
+			};
+		}
+	}
+
+	/// Generates a new Ulys that is greater than the previous one, using the
+	/// current time (UTC)
+	///
+	/// # Errors
+	///
+	/// A `MonotonicError::Overflow` will be returned when the random portion
+	/// cannot be incremented anymore within the current millisecond.
+	pub fn generate(&mut self) -> Result<Ulys, MonotonicError> {
+		self.generate_from_datetime(self.now())
+	}
+
+	/// Generates a new Ulys that is greater than the previous one, using the
+	/// current time (UTC), waiting for the clock when needed
+	///
+	/// When the random portion cannot be incremented anymore within the
+	/// current millisecond, the thread sleeps until the clock reaches the next
+	/// millisecond and tries again, so a value is always returned. This also
+	/// waits out a clock that went backwards once the previous Ulys has
+	/// overflowed.
+	pub fn generate_or_wait(&mut self) -> Ulys {
+		loop {
+			match self.generate() {
+				Ok(ulys) => return ulys,
+				Err(MonotonicError::Overflow) => {
+					let next = self.previous.datetime() + Duration::from_millis(1);
+					thread::sleep(next.duration_since(self.now()).unwrap_or_default());
+				}
+			}
+		}
+	}
+
+	/// Iterates over successive results of [`Generator::generate`]
+	///
+	/// The iterator never ends: bound it with `take`. An overflow is yielded
+	/// as an `Err` rather than ending the iteration, so collecting into a
+	/// `Result` stops at the first one, while a caller iterating by hand can
+	/// wait for the next millisecond and keep going.
+	///
+	/// # Examples
+	/// ```
+	/// # use ulys::{Generator, MonotonicError};
+	/// let mut generator = Generator::new();
+	///
+	/// let ids = generator.iter().take(1000).collect::<Result<Vec<_>, _>>()?;
+	///
+	/// assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+	/// # Ok::<(), MonotonicError>(())
+	/// ```
+	pub fn iter(&mut self) -> impl Iterator<Item = Result<Ulys, MonotonicError>> + '_ {
+		core::iter::from_fn(|| Some(self.generate()))
+	}
+
+	/// Generates `n` Ulys values greater than the previous one, using the
+	/// current time (UTC)
+	///
+	/// The returned vector is sorted and free of duplicates by construction,
+	/// since every Ulys is strictly greater than the one before it.
+	///
+	/// # Errors
+	///
+	/// A `MonotonicError::Overflow` will be returned when the random portion
+	/// cannot be incremented anymore within the current millisecond. The IDs
+	/// generated before the overflow are discarded, but the generator keeps
+	/// its state past them.
+	pub fn generate_batch(&mut self, n: usize) -> Result<Vec<Ulys>, MonotonicError> {
+		self.iter().take(n).collect()
+	}
+
+	/// Generates a new Ulys that is greater than the previous one, using the
+	/// given datetime
+	///
+	/// # Errors
+	///
+	/// A `MonotonicError::Overflow` will be returned when the random portion
+	/// cannot be incremented anymore within the given millisecond.
+	pub fn generate_from_datetime(&mut self, datetime: SystemTime) -> Result<Ulys, MonotonicError> {
+		match self.rng.take() {
+			Some(mut rng) => {
+				let result = self.generate_from_datetime_with_source(datetime, &mut *rng);
+				self.rng = Some(rng);
+				result
+			}
+			None => self.generate_from_datetime_with_source(datetime, &mut rand::rng()),
+		}
+	}
+
+	/// Generates a new Ulys that is greater than the previous one, using the
+	/// current time (UTC) and the given random source
+	///
+	/// # Errors
+	///
+	/// A `MonotonicError::Overflow` will be returned when the random portion
+	/// cannot be incremented anymore within the current millisecond.
+	pub fn generate_with_source<R>(&mut self, source: &mut R) -> Result<Ulys, MonotonicError>
+	where
+		R: Rng + ?Sized,
+	{
+		self.generate_from_datetime_with_source(self.now(), source)
+	}
+
+	/// Generates a new Ulys that is greater than the previous one, using the
+	/// given datetime and random source
+	///
+	/// A datetime earlier than the previous Ulys is treated as the same
+	/// millisecond, so a clock going backwards does not break the ordering.
+	///
+	/// # Errors
+	///
+	/// A `MonotonicError::Overflow` will be returned when the random portion
+	/// cannot be incremented anymore within the given millisecond.
+	pub fn generate_from_datetime_with_source<R>(
+		&mut self,
+		datetime: SystemTime,
+		source: &mut R,
+	) -> Result<Ulys, MonotonicError>
+	where
+		R: Rng + ?Sized,
+	{
+		let timestamp = Ulys::unix_millis(datetime);
+
+		self.previous = if timestamp <= self.previous.timestamp_ms() {
+			self.increment().ok_or(MonotonicError::Overflow)?
+		} else {
+			Self::draw(self.tenant, timestamp, source)
+		};
+
+		Ok(self.output(self.previous))
+	}
+
+	/// Creates `n` strictly increasing IDs spread evenly over the window from
+	/// `start` to `end`, both inclusive
+	///
+	/// The milliseconds of the window are shared out evenly, in order, and the
+	/// IDs falling into the same millisecond get consecutive random portions
+	/// starting from a random value drawn low enough that they never
+	/// overflow. This makes deterministic-shape load-test data: the count,
+	/// ordering and timestamp bounds are guaranteed, only the random portions
+	/// vary between calls. An `end` before `start` is treated as the single
+	/// millisecond of `start`.
+	///
+	/// # Errors
+	///
+	/// A `MonotonicError::Overflow` will be returned when `n` exceeds the
+	/// capacity of the window, 2^48 IDs per millisecond.
+	pub fn fill_range(
+		start: SystemTime,
+		end: SystemTime,
+		n: usize,
+	) -> Result<Vec<Ulys>, MonotonicError> {
+		let source = &mut rand::rng();
+		let start_ms = Ulys::unix_millis(start);
+		let span = u128::from(Ulys::unix_millis(end).saturating_sub(start_ms)) + 1;
+		let count = n as u128;
+
+		if count > span * (u128::from(Ulys::RAND_MAX) + 1) {
+			return Err(MonotonicError::Overflow);
+		}
+
+		// Offsets are below `span`, which fits in 48 bits.
+		#[allow(clippy::cast_possible_truncation)]
+		let timestamp_of = |index: usize| start_ms + (index as u128 * span / count) as u64;
+
+		let mut ids = Vec::with_capacity(n);
+		while ids.len() < n {
+			let first = ids.len();
+			let timestamp = timestamp_of(first);
+			let run = (first..n)
+				.take_while(|&index| timestamp_of(index) == timestamp)
+				.count() as u64;
+
+			let random = source.random_range(0..=Ulys::RAND_MAX - (run - 1));
+			ids.extend((random..random + run).map(|random| Ulys::from_parts(timestamp, random)));
+		}
+
+		Ok(ids)
+	}
+
+	/// Makes the checksum of the IDs to come keyed by `key`
+	///
+	/// The IDs pass [`Ulys::is_valid_keyed`] with the same key, and not the
+	/// plain [`Ulys::is_valid`], see [`Ulys::new_keyed`] for the guarantees.
+	/// Only the checksum changes, so the IDs are still monotonic.
+	///
+	/// # Examples
+	/// ```
+	/// # use ulys::Generator;
+	/// let key = [7; 32];
+	/// let mut generator = Generator::new().with_key(key);
+	///
+	/// let ulys1 = generator.generate().unwrap();
+	/// let ulys2 = generator.generate().unwrap();
+	///
+	/// assert!(ulys1 < ulys2);
+	/// assert!(ulys2.is_valid_keyed(&key));
+	/// ```
+	#[must_use]
+	pub fn with_key(mut self, key: [u8; 32]) -> Generator {
+		self.key = Some(key);
+		self
+	}
+
+	/// Embeds `tenant` in the top 16 random bits of the IDs to come, see
+	/// [`TenantGenerator`](crate::TenantGenerator)
+	pub(crate) fn with_tenant(mut self, tenant: u16) -> Generator {
+		self.tenant = Some(tenant);
+		self
+	}
+
+	/// Creates a Ulys in the given millisecond with a fresh random portion,
+	/// keeping the tenant if any
+	fn draw<R>(tenant: Option<u16>, timestamp: u64, source: &mut R) -> Ulys
+	where
+		R: Rng + ?Sized,
+	{
+		match tenant {
+			Some(tenant) => Ulys::from_parts(
+				timestamp,
+				u64::from(tenant) << 32 | u64::from(source.random::<u32>()),
+			),
+			None => Ulys::from_timestamp_with_source(timestamp, source),
+		}
+	}
+
+	/// Increments the previous Ulys, without carrying into the tenant if any
+	fn increment(&self) -> Option<Ulys> {
+		let next = self.previous.increment()?;
+
+		match self.tenant {
+			Some(tenant) if next.shard() != tenant => None,
+			_ => Some(next),
+		}
+	}
+
+	/// Gets the current time, from the monotonic anchor if any
+	fn now(&self) -> SystemTime {
+		match self.anchor {
+			Some((wall, mono)) => wall + mono.elapsed(),
+			None => time_utils::now(),
+		}
+	}
+
+	/// Converts the internal state into the Ulys handed to the caller
+	fn output(&self, ulys: Ulys) -> Ulys {
+		let ulys = if self.scattered {
+			let scattered = ulys.random().reverse_bits() >> (64 - Ulys::RAND_BITS);
+			Ulys::from_parts(ulys.timestamp_ms(), scattered)
+		} else {
+			ulys
+		};
+
+		match &self.key {
+			Some(key) => ulys.with_keyed_checksum(key),
+			None => ulys,
+		}
+	}
+}
+
+impl fmt::Debug for Generator {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		// The RNG is not `Debug`, only its presence is shown.
+		f.debug_struct("Generator")
+			.field("previous", &self.previous)
+			.field("scattered", &self.scattered)
+			.field("anchor", &self.anchor)
+			.field("owns_rng", &self.rng.is_some())
+			.field("tenant", &self.tenant)
+			.field("keyed", &self.key.is_some())
+			.finish()
+	}
+}
+
+/// Error while trying to generate a monotonic Ulys
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MonotonicError {
+	/// The random portion would overflow within the same millisecond
+	Overflow,
+}
+
+impl fmt::Display for MonotonicError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		let text = match *self {
+			MonotonicError::Overflow => "ulys random bits would overflow",
+		};
+		write!(f, "{text}")
+	}
+}
+
+impl std::error::Error for MonotonicError {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	#[test]
+	fn test_generator_order() {
+		let dt = SystemTime::now();
+		let mut generator = Generator::new();
+
+		let ulys1 = generator.generate_from_datetime(dt).unwrap();
+		let ulys2 = generator.generate_from_datetime(dt).unwrap();
+		let ulys3 = generator
+			.generate_from_datetime(dt - Duration::from_millis(1))
+			.unwrap();
+		let ulys4 = generator
+			.generate_from_datetime(dt + Duration::from_millis(1))
+			.unwrap();
+
+		assert!(ulys1 < ulys2);
+		assert!(ulys2 < ulys3);
+		assert!(ulys3 < ulys4);
+		assert_eq!(ulys2.random(), ulys1.random() + 1);
+		assert_eq!(ulys3.timestamp_ms(), ulys1.timestamp_ms());
+		assert!([ulys1, ulys2, ulys3, ulys4].iter().all(Ulys::is_valid));
+	}
+
+	#[test]
+	fn test_generator_overflow() {
+		let dt = SystemTime::now();
+		let mut generator = Generator::new();
+		generator.previous = Ulys::from_parts(Ulys::unix_millis(dt), Ulys::RAND_MAX);
+
+		assert_eq!(
+			generator.generate_from_datetime(dt),
+			Err(MonotonicError::Overflow)
+		);
+		assert!(generator
+			.generate_from_datetime(dt + Duration::from_millis(1))
+			.is_ok());
+	}
+
+	#[test]
+	fn test_generator_or_wait() {
+		let mut generator = Generator::new();
+		let now = Ulys::unix_millis(SystemTime::now());
+		generator.previous = Ulys::from_parts(now, Ulys::RAND_MAX);
+
+		let ulys = generator.generate_or_wait();
+		assert!(ulys.timestamp_ms() > now);
+		assert!(ulys.is_valid());
+		assert!(generator.generate_or_wait() > ulys);
+	}
+
+	#[test]
+	fn test_generator_scattered() {
+		let dt = SystemTime::now();
+		let mut generator = Generator::new().with_scattered_random();
+
+		let ulys1 = generator.generate_from_datetime(dt).unwrap();
+		let ulys2 = generator.generate_from_datetime(dt).unwrap();
+		let ulys3 = generator
+			.generate_from_datetime(dt + Duration::from_millis(1))
+			.unwrap();
+
+		assert_ne!(ulys1, ulys2);
+		assert_eq!(ulys1.timestamp_ms(), ulys2.timestamp_ms());
+		assert!(ulys1.max(ulys2) < ulys3);
+		assert!([ulys1, ulys2, ulys3].iter().all(Ulys::is_valid));
+
+		// Consecutive IDs differ in the top random bits, not the lowest ones.
+		let diff = ulys1.random() ^ ulys2.random();
+		assert!(diff.leading_zeros() == 64 - u32::from(Ulys::RAND_BITS));
+	}
+
+	#[test]
+	fn test_generator_warmup() {
+		let stale = SystemTime::now() - Duration::from_hours(1);
+		let mut generator = Generator::new();
+		generator.generate_from_datetime(stale).unwrap();
+
+		let before = Ulys::unix_millis(SystemTime::now());
+		generator.warmup();
+		let warmed = generator.previous;
+		assert!(warmed.timestamp_ms() >= before);
+
+		let ulys = generator.generate().unwrap();
+		assert!(ulys > warmed);
+		assert!(ulys.timestamp_ms() >= before);
+
+		// A generator ahead of the clock is left untouched.
+		let future = SystemTime::now() + Duration::from_hours(1);
+		let ahead = generator.generate_from_datetime(future).unwrap();
+		generator.warmup();
+		assert_eq!(generator.previous, ahead);
+	}
+
+	#[test]
+	fn test_generator_iter() {
+		let mut generator = Generator::new();
+		let ids = generator
+			.iter()
+			.take(1000)
+			.collect::<Result<Vec<_>, _>>()
+			.unwrap();
+
+		assert_eq!(ids.len(), 1000);
+		assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+		assert_eq!(generator.previous, ids[999]);
+	}
+
+	#[test]
+	fn test_generator_batch() {
+		let mut generator = Generator::new();
+		let first = generator.generate().unwrap();
+		let ids = generator.generate_batch(1000).unwrap();
+
+		assert_eq!(ids.len(), 1000);
+		assert!(first < ids[0]);
+		assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+		assert_eq!(generator.generate_batch(0), Ok(Vec::new()));
+
+		let dt = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+		generator.previous = Ulys::from_parts(Ulys::unix_millis(dt), Ulys::RAND_MAX - 1);
+		time_utils::set_mock_now(dt);
+		let result = generator.generate_batch(2);
+		time_utils::clear_mock_now();
+		assert_eq!(result, Err(MonotonicError::Overflow));
+	}
+
+	#[test]
+	fn test_generator_iter_yields_overflow() {
+		let dt = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+		let mut generator = Generator::new();
+		generator.previous = Ulys::from_parts(Ulys::unix_millis(dt), Ulys::RAND_MAX - 1);
+
+		time_utils::set_mock_now(dt);
+		let results: Vec<_> = generator.iter().take(3).collect();
+		time_utils::clear_mock_now();
+
+		assert!(results[0].is_ok());
+		assert_eq!(results[1..], [Err(MonotonicError::Overflow); 2]);
+	}
+
+	#[test]
+	fn test_generator_with_rng() {
+		let dt = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+		let mut generator1 = Generator::new().with_rng(StdRng::seed_from_u64(42));
+		let mut generator2 = Generator::new().with_rng(StdRng::seed_from_u64(42));
+
+		for offset in [0, 0, 1, 5, 5] {
+			let dt = dt + Duration::from_millis(offset);
+			let ulys = generator1.generate_from_datetime(dt).unwrap();
+
+			assert_eq!(generator2.generate_from_datetime(dt), Ok(ulys));
+			assert!(ulys.is_valid());
+		}
+
+		let mut generator3 = Generator::new().with_rng(StdRng::seed_from_u64(43));
+		assert_ne!(
+			generator1.generate_from_datetime(dt + Duration::from_millis(9)),
+			generator3.generate_from_datetime(dt + Duration::from_millis(9))
+		);
+	}
+
+	#[test]
+	fn test_generator_with_key() {
+		let dt = SystemTime::now();
+		let mut generator = Generator::new().with_key([1; 32]);
+
+		let ids: Vec<Ulys> = [
+			dt,
+			dt,
+			dt - Duration::from_millis(1),
+			dt + Duration::from_millis(1),
+		]
+		.into_iter()
+		.map(|dt| generator.generate_from_datetime(dt).unwrap())
+		.collect();
+
+		assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+		assert!(ids.iter().all(|ulys| ulys.is_valid_keyed(&[1; 32])));
+		assert!(!ids.iter().any(|ulys| ulys.is_valid_keyed(&[2; 32])));
+		assert!(!ids.iter().any(Ulys::is_valid));
+	}
+
+	#[test]
+	fn test_fill_range() {
+		let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+		let end = start + Duration::from_millis(9);
+
+		for n in [0, 1, 7, 10, 1000] {
+			let ids = Generator::fill_range(start, end, n).unwrap();
+
+			assert_eq!(ids.len(), n);
+			assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+			assert!(ids.iter().all(Ulys::is_valid));
+			assert!(ids
+				.iter()
+				.all(|ulys| ulys.datetime() >= start && ulys.datetime() <= end));
+		}
+
+		// Spread evenly: 1000 IDs over 10 milliseconds.
+		let ids = Generator::fill_range(start, end, 1000).unwrap();
+		assert_eq!(ids[0].datetime(), start);
+		assert_eq!(ids[999].datetime(), end);
+		assert_eq!(ids[99].timestamp_delta_ms(&ids[0]), 0);
+		assert_eq!(ids[100].timestamp_delta_ms(&ids[0]), 1);
+	}
+
+	#[test]
+	fn test_fill_range_capacity() {
+		let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+		assert_eq!(
+			Generator::fill_range(start, start - Duration::from_secs(1), 3)
+				.unwrap()
+				.len(),
+			3
+		);
+		if usize::BITS > 48 {
+			assert_eq!(
+				Generator::fill_range(start, start, (1 << 48) + 1),
+				Err(MonotonicError::Overflow)
+			);
+		}
+	}
+
+	#[test]
+	fn test_generator_options_combine() {
+		let wall = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+		let mono = Instant::now();
+		let build = || {
+			Generator::new()
+				.with_rng(StdRng::seed_from_u64(42))
+				.with_monotonic_anchor(wall, mono)
+				.with_key([3; 32])
+		};
+		let mut generator1 = build();
+		let mut generator2 = build();
+
+		// The anchored clock is far from the current one, and both
+		// generators draw the same random portions.
+		let ulys = generator1.generate().unwrap();
+		assert!(ulys.datetime() >= wall);
+		assert!(ulys.datetime() < wall + Duration::from_hours(1));
+		assert!(ulys.is_valid_keyed(&[3; 32]));
+		assert_eq!(generator2.generate_from_datetime(ulys.datetime()), Ok(ulys));
+	}
+
+	#[test]
+	fn test_generator_monotonic_anchor() {
+		let wall = SystemTime::now();
+		let mut anchored = Generator::new().with_monotonic_anchor(wall, Instant::now());
+		let mut generator = Generator::new();
+
+		let ulys1 = anchored.generate().unwrap();
+		// The wall clock jumps an hour backwards.
+		time_utils::set_mock_now(wall - Duration::from_hours(1));
+		let ulys2 = anchored.generate().unwrap();
+		let unanchored = generator.generate().unwrap();
+		time_utils::clear_mock_now();
+
+		assert!(ulys1 < ulys2);
+		assert!(ulys1.datetime() + Duration::from_millis(1) > wall);
+		assert!(ulys2.datetime() >= ulys1.datetime());
+		assert!(unanchored < ulys1);
+	}
+}
@@ -0,0 +1,72 @@
+//! Conversions between ULYS and HTTP header values.
+
+use crate::{Ulys, UlysError};
+use http::HeaderValue;
+
+impl Ulys {
+	/// Creates a header value holding the canonical string of this Ulys
+	#[must_use]
+	#[allow(clippy::missing_panics_doc)] // Crockford Base32 is visible ASCII
+	pub fn to_header_value(&self) -> HeaderValue {
+		HeaderValue::from_bytes(&self.encode_to_array()).expect("Crockford Base32 is visible ASCII")
+	}
+
+	/// Creates a Ulys from a header value
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the header value is not a valid
+	/// Ulys string. A non-ASCII byte is reported as `UlysError::InvalidCharAt`
+	/// like any other byte outside the alphabet.
+	pub fn from_header_value(value: &HeaderValue) -> Result<Ulys, UlysError> {
+		Ulys::from_ascii_bytes(value.as_bytes())
+	}
+}
+
+impl From<Ulys> for HeaderValue {
+	fn from(ulys: Ulys) -> Self {
+		ulys.to_header_value()
+	}
+}
+
+impl TryFrom<&HeaderValue> for Ulys {
+	type Error = UlysError;
+
+	fn try_from(value: &HeaderValue) -> Result<Self, Self::Error> {
+		Ulys::from_header_value(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn header_value_cycle() {
+		let ulys = Ulys::new();
+		let value = ulys.to_header_value();
+
+		assert_eq!(value.to_str().unwrap(), ulys.to_string());
+		assert_eq!(Ulys::from_header_value(&value), Ok(ulys));
+		assert_eq!(Ulys::try_from(&HeaderValue::from(ulys)), Ok(ulys));
+	}
+
+	#[test]
+	fn header_value_invalid() {
+		// cSpell:disable-next-line
+		let value = HeaderValue::from_bytes(b"068dkwmn3a441g20mzbsmyk5b\xe9").unwrap();
+		assert_eq!(
+			Ulys::from_header_value(&value),
+			Err(UlysError::InvalidCharAt {
+				index: 25,
+				byte: 0xe9
+			})
+		);
+
+		let value = HeaderValue::from_static("not-a-ulys");
+		assert_eq!(
+			Ulys::from_header_value(&value),
+			Err(UlysError::ParseInvalidLength)
+		);
+	}
+}
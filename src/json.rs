@@ -0,0 +1,98 @@
+//! Conversions between ULYS and `serde_json` values.
+
+use crate::{Ulys, UlysError};
+use serde_json::Value;
+
+impl Ulys {
+	/// Creates a JSON string value holding the canonical string of this Ulys
+	///
+	/// This is the same representation as the `Serialize` implementation,
+	/// without going through a serializer.
+	#[must_use]
+	pub fn to_json_value(&self) -> Value {
+		Value::String(self.to_string())
+	}
+
+	/// Creates a Ulys from a JSON value holding either its string or its
+	/// integer representation
+	///
+	/// Integers are the inner `u128`, as written by
+	/// [`ulys_as_u128`](crate::serde::ulys_as_u128). Without the
+	/// `arbitrary_precision` feature of `serde_json`, a `Value` cannot hold
+	/// integers above `u64::MAX`, so only the string form can carry IDs with
+	/// a real timestamp.
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the string is not a valid Ulys
+	/// string, or `UlysError::InvalidJsonType` when the value is neither a
+	/// string nor a nonnegative integer.
+	pub fn from_json_value(value: &Value) -> Result<Ulys, UlysError> {
+		match value {
+			Value::String(text) => Ulys::from_string(text),
+			Value::Number(number) => number.as_u128().map(Ulys).ok_or(UlysError::InvalidJsonType),
+			_ => Err(UlysError::InvalidJsonType),
+		}
+	}
+}
+
+impl From<Ulys> for Value {
+	fn from(ulys: Ulys) -> Self {
+		ulys.to_json_value()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn json_value_string_cycle() {
+		let ulys = Ulys::new();
+		let value = ulys.to_json_value();
+
+		assert_eq!(value, Value::String(ulys.to_string()));
+		assert_eq!(value, serde_json::to_value(ulys).unwrap());
+		assert_eq!(Ulys::from_json_value(&value), Ok(ulys));
+		assert_eq!(json!({ "id": ulys }), json!({ "id": value }));
+	}
+
+	#[test]
+	fn json_value_from_number() {
+		let ulys = Ulys(0x1234_5678_9abc_def0);
+
+		assert_eq!(
+			Ulys::from_json_value(&json!(0x1234_5678_9abc_def0_u64)),
+			Ok(ulys)
+		);
+		assert_eq!(
+			Ulys::from_json_value(&json!(-1)),
+			Err(UlysError::InvalidJsonType)
+		);
+		assert_eq!(
+			Ulys::from_json_value(&json!(1.5)),
+			Err(UlysError::InvalidJsonType)
+		);
+	}
+
+	#[test]
+	fn json_value_rejects_other_values() {
+		assert_eq!(
+			Ulys::from_json_value(&json!("not a ulys")),
+			Err(UlysError::ParseInvalidLength)
+		);
+		assert_eq!(
+			Ulys::from_json_value(&json!(null)),
+			Err(UlysError::InvalidJsonType)
+		);
+		assert_eq!(
+			Ulys::from_json_value(&json!([])),
+			Err(UlysError::InvalidJsonType)
+		);
+		assert_eq!(
+			Ulys::from_json_value(&json!(true)),
+			Err(UlysError::InvalidJsonType)
+		);
+	}
+}
@@ -1,21 +1,114 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "actix")]
+mod actix;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "atomic")]
+mod atomic;
+#[cfg(feature = "axum")]
+mod axum;
+mod base32;
+mod base62;
+#[cfg(feature = "bitvec")]
+mod bitvec;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "std")]
+mod generator;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "serde_json")]
+mod json;
+mod non_nil;
 #[cfg(feature = "postgres")]
 mod postgres;
+#[cfg(feature = "tokio")]
+mod rate_limited;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
 #[cfg(feature = "serde")]
 pub mod serde;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(any(feature = "sqlx-postgres", feature = "sqlx-sqlite"))]
+mod sqlx;
+#[cfg(feature = "std")]
+mod tenant;
+mod test_vectors;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "std")]
+pub mod time_utils;
+#[cfg(feature = "std")]
+mod timed;
+mod typed;
 #[cfg(feature = "uuid")]
 mod uuid;
+#[cfg(feature = "valuable")]
+mod valuable;
+#[cfg(feature = "zeroize")]
+pub mod zeroize;
 
-use base32::Alphabet;
+#[cfg(feature = "atomic")]
+pub use atomic::AtomicGenerator;
+#[cfg(feature = "std")]
+pub use generator::{Generator, MonotonicError};
+pub use non_nil::NonNilUlys;
+#[cfg(feature = "tokio")]
+pub use rate_limited::RateLimitedGenerator;
+#[cfg(feature = "std")]
+pub use tenant::TenantGenerator;
+pub use test_vectors::TEST_VECTORS;
+#[cfg(feature = "std")]
+pub use timed::TimedUlys;
+pub use typed::TypedUlys;
+
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
 use core::fmt;
-use rand::RngExt;
+#[cfg(feature = "std")]
+use core::hash::{Hash, Hasher};
+use core::ops::Range;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use rand::rngs::SmallRng;
+#[cfg(feature = "std")]
+use rand::{Rng, RngExt, SeedableRng};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(feature = "std")]
+use std::hash::DefaultHasher;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, PoisonError};
+#[cfg(feature = "std")]
 use std::time::{Duration, SystemTime};
-use xxhash_rust::xxh3::xxh3_64;
+use xxhash_rust::xxh3::{xxh3_128, xxh3_64, xxh3_64_with_seed};
 
 #[derive(Debug, PartialEq)]
 pub enum UlysError {
 	ParseInvalidLength,
 	ParseBase32Decode,
 	ParseToArray,
+	InvalidUuid,
+	InvalidCharAt { index: usize, byte: u8 },
+	InvalidJsonType,
+	ValueOverflow,
 }
 
 impl fmt::Display for UlysError {
@@ -24,14 +117,80 @@ impl fmt::Display for UlysError {
 			UlysError::ParseInvalidLength => "invalid length",
 			UlysError::ParseBase32Decode => "invalid character",
 			UlysError::ParseToArray => "invalid array",
+			UlysError::InvalidUuid => "invalid uuid",
+			UlysError::InvalidCharAt { index, byte } => {
+				let byte = core::ascii::escape_default(byte);
+				return write!(f, "invalid character '{byte}' at index {index}");
+			}
+			UlysError::InvalidJsonType => {
+				"json value is neither a string nor a nonnegative integer"
+			}
+			UlysError::ValueOverflow => "value does not fit in 128 bits",
 		};
 		write!(f, "{text}")
 	}
 }
 
-#[derive(Debug, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
+impl core::error::Error for UlysError {}
+
+#[derive(Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
+#[repr(transparent)]
 pub struct Ulys(pub u128);
 
+/// The bit layout of a Ulys, as reported by [`Ulys::bit_layout`]
+///
+/// Offsets count from the least significant bit of the inner `u128`, so a
+/// field is read with `(ulys.0 >> offset) & ((1 << bits) - 1)`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BitLayout {
+	/// The number of bits in the time portion
+	pub time_bits: u8,
+	/// The number of bits in the random portion
+	pub rand_bits: u8,
+	/// The number of bits in the checksum
+	pub check_bits: u8,
+	/// The offset of the time portion
+	pub time_offset: u8,
+	/// The offset of the random portion
+	pub rand_offset: u8,
+	/// The offset of the checksum
+	pub check_offset: u8,
+}
+
+/// A timestamp regression found by [`Ulys::find_ordering_violation`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct OrderingViolation {
+	/// Index of `curr` in the checked slice
+	pub index: usize,
+	/// The Ulys right before the regression
+	pub prev: Ulys,
+	/// The first Ulys with an earlier timestamp than `prev`
+	pub curr: Ulys,
+	/// Timestamp of `curr` minus timestamp of `prev`, always negative
+	pub delta_ms: i64,
+}
+
+/// A checksum mismatch reported by [`Ulys::verify`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ChecksumError {
+	/// The checksum stored in the Ulys
+	pub stored: u32,
+	/// The checksum computed from the timestamp and random portions
+	pub expected: u32,
+}
+
+impl fmt::Display for ChecksumError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+		write!(
+			f,
+			"checksum mismatch (expected {:#010x}, got {:#010x})",
+			self.expected, self.stored
+		)
+	}
+}
+
+impl core::error::Error for ChecksumError {}
+
 impl Ulys {
 	/// Length of a string-encoded Ulys
 	pub const ULYS_LEN: usize = 26;
@@ -43,188 +202,2350 @@ impl Ulys {
 	/// The number of bits in a Ulys checksum
 	pub const CHECK_BITS: u8 = 32;
 
+	/// The nil Ulys, with all bits set to zero, the lowest possible key in
+	/// range scans
+	///
+	/// Its checksum does not match its data, so it does not pass
+	/// [`Ulys::is_valid`]: use it only as a lower bound, not as an ID.
+	pub const NIL: Ulys = Ulys(0);
+	/// The Ulys with all bits set to one, the highest possible key in range
+	/// scans
+	///
+	/// Its checksum does not match its data, so it does not pass
+	/// [`Ulys::is_valid`]: use it only as an upper bound, not as an ID.
+	pub const MAX: Ulys = Ulys(u128::MAX);
+
+	/// Length of a string-encoded Ulys without its checksum
+	const CHECKSUMLESS_LEN: usize = 20;
+
+	/// The largest value of the timestamp portion
+	const TIME_MAX: u64 = (1 << Self::TIME_BITS) - 1;
+	/// The largest value of the random portion
+	const RAND_MAX: u64 = (1 << Self::RAND_BITS) - 1;
+
+	/// Describes the bit layout of a Ulys
+	///
+	/// The report is derived from [`Ulys::TIME_BITS`], [`Ulys::RAND_BITS`]
+	/// and [`Ulys::CHECK_BITS`], so tooling can locate each field without
+	/// hardcoding offsets.
+	#[must_use]
+	pub const fn bit_layout() -> BitLayout {
+		BitLayout {
+			time_bits: Self::TIME_BITS,
+			rand_bits: Self::RAND_BITS,
+			check_bits: Self::CHECK_BITS,
+			time_offset: Self::RAND_BITS + Self::CHECK_BITS,
+			rand_offset: Self::CHECK_BITS,
+			check_offset: 0,
+		}
+	}
+
 	/// Creates a new Ulys with the current time (UTC)
+	#[cfg(feature = "std")]
 	#[must_use]
 	pub fn new() -> Self {
-		Self::from_datetime(SystemTime::now())
+		Self::from_datetime(time_utils::now())
 	}
 
-	/// Creates a Ulys from a Crockford Base32 encoded string
-	///
-	/// # Errors
+	/// Creates a new Ulys with the current time (UTC) using a fast,
+	/// non-cryptographic random source
 	///
-	/// An `UlysError` will be returned when the given string is not formatted
-	/// properly.
-	pub fn from_string(s: &str) -> Result<Ulys, UlysError> {
-		if s.len() != Ulys::ULYS_LEN {
-			return Err(UlysError::ParseInvalidLength);
+	/// The random portion comes from a thread-local [`SmallRng`] seeded from
+	/// the clock, the process and the thread, so this never waits on the
+	/// operating system for entropy, not even on the first call of a thread.
+	/// The resulting IDs are unique enough for request or trace identifiers
+	/// but are predictable: never use them as tokens or secrets, use
+	/// [`Ulys::new`] instead.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn new_fast() -> Self {
+		thread_local! {
+			static FAST_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::seed_from_u64(fast_seed()));
 		}
 
-		let value = base32::decode(Alphabet::Crockford, s)
-			.ok_or(UlysError::ParseBase32Decode)?
-			.try_into()
-			.map_err(|_| UlysError::ParseToArray)?;
+		let timestamp = Self::unix_millis(time_utils::now());
+		FAST_RNG.with_borrow_mut(|source| Self::from_timestamp_with_source(timestamp, source))
+	}
+
+	/// Creates a new Ulys with the current time (UTC) that is greater than
+	/// every Ulys previously returned by this function in the process
+	///
+	/// This is backed by a process-wide [`Generator`] behind a mutex, so IDs
+	/// are strictly increasing across all threads without holding a generator.
+	/// Every call takes the lock: under heavy contention from many threads,
+	/// prefer a [`Generator`] per thread when only per-thread ordering is
+	/// needed. When the random portion overflows within a millisecond, the
+	/// call sleeps until the clock moves on, still holding the lock.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn new_global_monotonic() -> Self {
+		static GLOBAL: Mutex<Generator> = Mutex::new(Generator::new());
 
-		Ok(Ulys(u128::from_be_bytes(value)))
+		// Generating never panics with the lock held, so a poisoned state is
+		// still consistent.
+		let mut generator = GLOBAL.lock().unwrap_or_else(PoisonError::into_inner);
+		generator.generate_or_wait()
 	}
 
-	/// Gets the datetime of when this Ulys was created accurate to 1ms
+	/// Creates `n` new Ulys values with the current time (UTC), in strictly
+	/// increasing order
+	///
+	/// The values come from a fresh [`Generator`], waiting for the clock in
+	/// the unlikely case the random portion overflows within a millisecond.
+	/// The returned vector is therefore sorted and free of duplicates by
+	/// construction, but it is not ordered relative to IDs created elsewhere.
+	#[cfg(feature = "std")]
 	#[must_use]
-	pub fn datetime(&self) -> SystemTime {
-		let stamp = self.timestamp_ms();
-		SystemTime::UNIX_EPOCH + Duration::from_millis(stamp)
+	pub fn new_batch(n: usize) -> Vec<Ulys> {
+		let mut generator = Generator::new();
+		(0..n).map(|_| generator.generate_or_wait()).collect()
 	}
 
-	/// Checks if the Ulys is valid
+	/// Creates a new Ulys with the current time (UTC) whose random portion is
+	/// not weak
+	///
+	/// Random values that are zero or whose Hamming weight (number of set
+	/// bits) is below `min_weight` are rejected and drawn again. `min_weight`
+	/// is capped at 24, the average weight of a uniform 48-bit value, so that
+	/// generation always terminates quickly: a zero value has a 2^-48
+	/// probability, and a weight below 16 happens about once every hundred
+	/// draws, so retries are negligible for sensible thresholds.
+	#[cfg(feature = "std")]
 	#[must_use]
-	pub fn is_valid(&self) -> bool {
-		let data = (self.0 >> Self::CHECK_BITS) << Self::CHECK_BITS;
-		let checksum = Ulys::checksum(data);
+	pub fn new_nonweak(min_weight: u32) -> Self {
+		Self::from_datetime_nonweak_with_source(time_utils::now(), min_weight, &mut rand::rng())
+	}
 
-		self.0 == (data | u128::from(checksum >> Self::CHECK_BITS))
+	/// Creates a new Ulys with the current time (UTC) that is not in `seen`,
+	/// and records it there
+	///
+	/// [`Ulys::new`] gives no hard uniqueness guarantee, only a probabilistic
+	/// one: two IDs collide only if they share a millisecond and 48 random
+	/// bits, see [`Ulys::birthday_collision_probability`]. For the paranoid,
+	/// this draws again on the astronomically unlikely collision with an ID of
+	/// `seen`, at the cost of keeping every previous ID in memory.
+	#[cfg(feature = "std")]
+	pub fn new_checked_unique(seen: &mut HashSet<Ulys>) -> Self {
+		Self::from_datetime_checked_unique_with_source(time_utils::now(), seen, &mut rand::rng())
 	}
 
-	/// Test if the Ulys is nil
+	/// Generates `n` IDs with [`Ulys::new`] and checks that they are all
+	/// different
+	///
+	/// This is a runtime self-test of the random source and the clock of the
+	/// platform: with a working random source, a duplicate among a few
+	/// million IDs is practically impossible, so `false` points at a broken
+	/// or predictable source rather than at bad luck.
+	#[cfg(feature = "std")]
 	#[must_use]
-	pub fn is_default(&self) -> bool {
-		self.0 == 0u128
+	pub fn stress_test_uniqueness(n: usize) -> bool {
+		let mut seen = HashSet::with_capacity(n);
+
+		(0..n).all(|_| seen.insert(Ulys::new()))
 	}
 
-	/// Creates a new Ulys with the given datetime
-	fn from_datetime(datetime: SystemTime) -> Self {
-		let timestamp = datetime
-			.duration_since(SystemTime::UNIX_EPOCH)
-			.unwrap_or(Duration::ZERO)
-			.as_millis();
+	/// Creates a new Ulys with the current time (UTC) whose checksum is keyed
+	///
+	/// The checksum is computed over the secret `key` followed by the
+	/// timestamp and random data, so someone without the key cannot build an
+	/// ID that passes [`Ulys::is_valid_keyed`]. Keyed IDs do not pass the
+	/// plain [`Ulys::is_valid`] check. [`Generator::with_key`] creates
+	/// monotonic keyed IDs.
+	///
+	/// This is lightweight integrity, not a MAC guarantee: the hash is not
+	/// cryptographic and only 32 bits are stored, so a forged ID is accepted
+	/// with a probability of 2^-32 per attempt.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn new_keyed(key: &[u8; 32]) -> Self {
+		Self::from_datetime(time_utils::now()).with_keyed_checksum(key)
+	}
 
-		let mut source = rand::rng();
-		let msb =
-			timestamp << (64 - Self::TIME_BITS) | u128::from(u64::from(source.random::<u16>()));
-		let rand = source.random::<u64>();
-		let data = msb << 64 | u128::from(rand << 32);
-		let checksum = Ulys::checksum(data);
-		let lsb = (rand << Self::CHECK_BITS) | checksum >> Self::CHECK_BITS;
+	/// Creates a new Ulys with the current time (UTC) carrying a shard key
+	///
+	/// `shard` fills the top 16 bits of the random portion, right after the
+	/// timestamp, and only the remaining 32 bits are drawn from `source`. IDs
+	/// of a shard thus stay contiguous within each millisecond, which keeps
+	/// per-shard range scans efficient, at the cost of 16 bits of entropy:
+	/// collisions within a shard become likely past tens of thousands of IDs
+	/// per millisecond.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn with_shard<R: Rng + ?Sized>(shard: u16, source: &mut R) -> Self {
+		let timestamp = Self::unix_millis(time_utils::now());
+		let random = u64::from(shard) << 32 | (source.random::<u64>() & 0xffff_ffff);
 
-		Self(msb << 64 | u128::from(lsb))
+		Self::from_parts(timestamp, random)
 	}
 
-	/// Creates a checksum for the given data
-	fn checksum(data: u128) -> u64 {
-		xxh3_64(data.to_be_bytes().as_slice())
+	/// Gets the shard key stored by [`Ulys::with_shard`]
+	///
+	/// This reads the top 16 bits of the random portion, so it is meaningless
+	/// for IDs that were not created with a shard key.
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)] // the random portion is 48 bits
+	pub fn shard(&self) -> u16 {
+		(self.random() >> 32) as u16
 	}
 
-	/// Gets the timestamp section of this Ulys
-	fn timestamp_ms(&self) -> u64 {
-		(self.0 >> (Self::RAND_BITS + Self::CHECK_BITS)) as u64
-	}
-}
+	/// Creates a Ulys derived deterministically from a string seed
+	///
+	/// Both the timestamp and the random portion come from the 128-bit XXH3
+	/// hash of `seed`, so the same seed always yields the same valid Ulys, on
+	/// every platform and run. This is meant for named, stable IDs in test
+	/// fixtures and documentation: the timestamp is not meaningful and the
+	/// IDs are trivially predictable.
+	///
+	/// # Examples
+	/// ```
+	/// # use ulys::Ulys;
+	/// assert_eq!(Ulys::from_seed("order-1"), Ulys::from_seed("order-1"));
+	/// assert_ne!(Ulys::from_seed("order-1"), Ulys::from_seed("order-2"));
+	/// ```
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)] // each portion is shifted to fit
+	pub fn from_seed(seed: &str) -> Self {
+		let hash = xxh3_128(seed.as_bytes());
 
-impl std::fmt::Display for Ulys {
-	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(
-			f,
-			"{}",
-			base32::encode(Alphabet::Crockford, &self.0.to_be_bytes()).to_lowercase()
+		Self::from_parts(
+			(hash >> (128 - Self::TIME_BITS)) as u64,
+			(hash >> Self::CHECK_BITS) as u64 & Self::RAND_MAX,
 		)
 	}
-}
 
-#[cfg(test)]
-mod tests {
-	use super::*;
+	/// Creates a Ulys from its timestamp and random portions, computing the
+	/// checksum
+	///
+	/// Only the lowest 48 bits of `timestamp_ms` and of `random` are used,
+	/// higher bits are silently truncated. The result always passes
+	/// [`Ulys::is_valid`], which makes this suited to test harnesses and
+	/// migrations that already know both portions.
+	///
+	/// # Examples
+	/// ```
+	/// # use ulys::Ulys;
+	/// let ulys = Ulys::from_parts(1_700_000_000_000, 0x1234_5678_9abc);
+	///
+	/// assert!(ulys.is_valid());
+	/// assert_eq!(ulys.to_string(), "065wzsb800938nkrkay14zwe7r");
+	/// ```
+	#[must_use]
+	pub fn from_parts(timestamp_ms: u64, random: u64) -> Self {
+		let data = u128::from(timestamp_ms & Self::TIME_MAX)
+			<< (Self::RAND_BITS + Self::CHECK_BITS)
+			| u128::from(random & Self::RAND_MAX) << Self::CHECK_BITS;
+		let checksum = Ulys::compute_checksum(data);
 
-	#[test]
-	fn test_is_default() {
-		let ulys = Ulys::new();
-		assert!(!ulys.is_default());
+		Self(data | u128::from(checksum >> Self::CHECK_BITS))
+	}
 
-		let nil = Ulys::default();
-		assert!(nil.is_default());
+	/// Creates a Ulys from its 16 big-endian bytes
+	#[must_use]
+	pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+		Ulys(u128::from_be_bytes(bytes))
+	}
 
-		assert_eq!(nil.to_string(), "00000000000000000000000000");
+	/// Gets the 16 big-endian bytes of this Ulys
+	///
+	/// Comparing these arrays byte by byte gives the same order as comparing
+	/// the Ulys themselves, so they can serve as fixed-width keys in sorted
+	/// key-value stores.
+	#[must_use]
+	pub const fn to_bytes(&self) -> [u8; 16] {
+		self.0.to_be_bytes()
 	}
 
-	#[test]
-	fn test_from_string() {
-		// cSpell:disable-next-line
-		let text = "068cbxpc1wy9d0v9gbhrg0020r";
-		let ulys = Ulys::from_string(text);
+	/// Creates a Ulys from the two `fixed64` halves of its protobuf
+	/// representation
+	///
+	/// `high` holds the 64 most significant bits, so the pair is the
+	/// big-endian split of the inner `u128`, see [`Ulys::to_proto_pair`].
+	#[must_use]
+	pub const fn from_proto_pair(high: u64, low: u64) -> Self {
+		Ulys((high as u128) << 64 | low as u128)
+	}
 
-		assert!(ulys.is_ok());
+	/// Splits this Ulys into two `fixed64` halves for protobuf messages
+	///
+	/// Schemas that cannot carry 128-bit integers conventionally represent an
+	/// ID as a `high` and a `low` `fixed64` field. The halves are the
+	/// big-endian split of the inner `u128`: `high` holds the timestamp and
+	/// the top 16 random bits, `low` the rest of the random portion and the
+	/// checksum.
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)] // each half is 64 bits
+	pub const fn to_proto_pair(&self) -> (u64, u64) {
+		((self.0 >> 64) as u64, self.0 as u64)
+	}
 
-		let data = ulys.expect("failed to deserialize");
-		assert_eq!(data.to_string(), text);
-		assert_eq!(data.0, 2_080_933_931_387_190_948_831_204_449_898_725_894);
+	/// Creates a Ulys from a Crockford Base32 encoded string
+	///
+	/// The parser is strict: only the 32 symbols of the Crockford alphabet are
+	/// accepted, in either case. The ambiguous letters `I`, `L` and `O` are
+	/// rejected, see [`Ulys::from_string_crockford_lenient`] to accept them.
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the given string is not formatted
+	/// properly. An invalid character is reported as
+	/// `UlysError::InvalidCharAt`, with the byte offset of the first offending
+	/// byte, so that user interfaces can point at it.
+	///
+	/// With the `simd` feature, on CPUs with AVX2 (detected at runtime with
+	/// `std`) or NEON, the characters are decoded with vector instructions,
+	/// with exactly the results of the scalar [`Ulys::decode`].
+	pub fn from_string(s: &str) -> Result<Ulys, UlysError> {
+		#[cfg(feature = "simd")]
+		return simd::decode(s.as_bytes())
+			.map(Ulys)
+			.map_err(UlysError::from);
+		#[cfg(not(feature = "simd"))]
+		Ulys::decode(s)
 	}
 
-	#[test]
-	fn test_from_string_invalid_length() {
-		let ulys = Ulys::from_string("ABC");
+	/// Creates a Ulys from a Crockford Base32 encoded string, in const
+	/// contexts
+	///
+	/// This is [`Ulys::from_string`] as a `const fn`, which also makes it
+	/// available to parse IDs without `std` or allocation.
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the given string is not formatted
+	/// properly, see [`Ulys::from_string`].
+	pub const fn decode(s: &str) -> Result<Ulys, UlysError> {
+		Ulys::from_ascii_bytes(s.as_bytes())
+	}
 
-		assert!(ulys.is_err());
-		assert_eq!(ulys.unwrap_err(), UlysError::ParseInvalidLength);
+	/// Creates a Ulys from Crockford Base32 encoded ASCII bytes
+	///
+	/// The bytes are decoded directly, without checking that they are valid
+	/// UTF-8 first, which suits IDs read from byte buffers such as network
+	/// frames. Any byte outside the alphabet is rejected, so non-ASCII input
+	/// fails the same way it does with [`Ulys::from_string`].
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the given bytes are not formatted
+	/// properly.
+	pub const fn from_ascii_bytes(bytes: &[u8]) -> Result<Ulys, UlysError> {
+		match base32::decode(bytes) {
+			Ok(value) => Ok(Ulys(value)),
+			Err(err) => Err(err.into_ulys_error()),
+		}
 	}
 
-	#[test]
-	fn test_from_string_invalid_letter() {
-		let ulys = Ulys::from_string("0000000000000u000000000000");
+	/// Encodes this Ulys as the 26 lowercase Crockford Base32 ASCII bytes of
+	/// its string form
+	///
+	/// This is the `Display` output without allocation, usable in const
+	/// contexts and without `std`.
+	#[must_use]
+	pub const fn encode_to_array(&self) -> [u8; Self::ULYS_LEN] {
+		base32::encode(self.0)
+	}
 
-		assert!(ulys.is_err());
-		assert_eq!(ulys.unwrap_err(), UlysError::ParseBase32Decode);
+	/// Creates a Ulys from a hand-typed Crockford Base32 encoded string
+	///
+	/// The Crockford specification asks decoders to read the letter `O` as the
+	/// digit `0`, and the letters `I` and `L` as the digit `1`, so that IDs
+	/// copied by hand are still understood. This parser applies those
+	/// substitutions (in either case) before decoding with
+	/// [`Ulys::from_string`]. Since the canonical encoding never produces
+	/// these letters, accepting them means several strings decode to the same
+	/// Ulys: only use this parser on human input, not for string comparison.
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the given string is not formatted
+	/// properly once the substitutions are applied.
+	pub fn from_string_crockford_lenient(s: &str) -> Result<Ulys, UlysError> {
+		let bytes: [u8; Ulys::ULYS_LEN] = s
+			.as_bytes()
+			.try_into()
+			.map_err(|_| UlysError::ParseInvalidLength)?;
+		let normalized = bytes.map(|byte| match byte {
+			b'O' | b'o' => b'0',
+			b'I' | b'i' | b'L' | b'l' => b'1',
+			_ => byte,
+		});
+		let normalized =
+			core::str::from_utf8(&normalized).map_err(|_| UlysError::ParseBase32Decode)?;
+
+		Ulys::from_string(normalized)
 	}
 
-	#[test]
-	fn test_dynamic() {
-		let ulys = Ulys::new();
-		let encoded = ulys.to_string();
-		let ulys2 = Ulys::from_string(&encoded).expect("failed to deserialize");
+	/// Creates a Ulys from a Crockford Base32 encoded string surrounded by
+	/// ASCII whitespace or control characters
+	///
+	/// IDs scraped from logs often carry a trailing `\r` from CRLF line
+	/// endings or a stray control byte. Those are trimmed from both ends
+	/// before decoding with [`Ulys::from_string`], but nothing is removed
+	/// from the middle of the string.
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the trimmed string is not
+	/// formatted properly.
+	pub fn from_string_trimmed(s: &str) -> Result<Ulys, UlysError> {
+		Ulys::from_string(s.trim_matches(|c: char| c.is_ascii_whitespace() || c.is_ascii_control()))
+	}
 
-		assert_eq!(ulys, ulys2);
+	/// Creates a Ulys from a string in any of the supported formats
+	///
+	/// The format is detected from the length and shape of the string:
+	/// - 26 characters: Crockford Base32, as parsed by [`Ulys::from_string`]
+	/// - 32 hexadecimal digits: the raw value in hexadecimal
+	/// - 36 characters, with the `uuid` feature: a hyphenated UUID
+	/// - otherwise, decimal digits: the raw `u128` value in decimal
+	///
+	/// Some strings fit several formats: a 26-digit number is read as
+	/// Crockford Base32 and a 32-digit number as hexadecimal, never as
+	/// decimal. Callers that exchange decimal values of those lengths should
+	/// parse them with [`u128::from_str`] instead.
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the string does not match any
+	/// supported format, `UlysError::InvalidUuid` for an invalid 36-character
+	/// UUID and `UlysError::ValueOverflow` for a decimal number above
+	/// `u128::MAX`.
+	pub fn parse_any(s: &str) -> Result<Ulys, UlysError> {
+		match s.len() {
+			Self::ULYS_LEN => Ulys::from_string(s),
+			// 32 hexadecimal digits always fit, the error cannot happen.
+			32 if s.bytes().all(|byte| byte.is_ascii_hexdigit()) => u128::from_str_radix(s, 16)
+				.map(Ulys)
+				.map_err(|_| UlysError::ValueOverflow),
+			#[cfg(feature = "uuid")]
+			36 => ::uuid::Uuid::parse_str(s)
+				.map(Ulys::from)
+				.map_err(|_| UlysError::InvalidUuid),
+			_ if s.is_empty() => Err(UlysError::ParseInvalidLength),
+			// Only digits are left, so parsing can only fail by overflowing.
+			_ if s.bytes().all(|byte| byte.is_ascii_digit()) => {
+				s.parse().map(Ulys).map_err(|_| UlysError::ValueOverflow)
+			}
+			_ => Err(UlysError::ParseInvalidLength),
+		}
 	}
 
-	#[test]
-	fn test_datetime() {
-		let dt = SystemTime::now();
-		let ulys = Ulys::from_datetime(dt);
+	/// Creates a Ulys from the string written by
+	/// [`Ulys::to_checksumless_string`], recomputing the checksum
+	///
+	/// The result is always valid, so this cannot detect corruption on the
+	/// wire: the checksum only protects the ID from this point on.
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the given string is not 20
+	/// Crockford Base32 characters.
+	pub fn from_checksumless_string(s: &str) -> Result<Ulys, UlysError> {
+		if s.len() != Self::CHECKSUMLESS_LEN {
+			return Err(UlysError::ParseInvalidLength);
+		}
 
-		assert!(ulys.datetime() <= dt);
-		assert!(ulys.datetime() + Duration::from_millis(1) >= dt);
+		// Padding with zeros fills the checksum bits, which are recomputed.
+		let padded = s.to_owned() + &"0".repeat(Self::ULYS_LEN - Self::CHECKSUMLESS_LEN);
+		let ulys = Ulys(base32::decode(padded.as_bytes())?);
+
+		Ok(Self::from_parts(ulys.timestamp_ms(), ulys.random()))
 	}
 
-	#[test]
-	fn test_timestamp() {
-		let dt = SystemTime::now();
-		let ulys = Ulys::from_datetime(dt);
-		let ts = dt
-			.duration_since(SystemTime::UNIX_EPOCH)
-			.unwrap()
-			.as_millis();
+	/// Normalizes a Crockford Base32 encoded Ulys to its canonical form
+	///
+	/// The string is parsed with [`Ulys::from_string`] and re-encoded, so IDs
+	/// received in upper or mixed case (e.g. from ULID systems) become
+	/// lowercase and can be compared or deduplicated as plain strings.
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the given string is not formatted
+	/// properly.
+	pub fn canonicalize_string(s: &str) -> Result<String, UlysError> {
+		Ulys::from_string(s).map(|ulys| ulys.to_string())
+	}
 
-		assert_eq!(u128::from(ulys.timestamp_ms()), ts);
+	/// Formats the 128 bits of this Ulys as a 36-character hyphenated
+	/// lowercase hex string, in the `8-4-4-4-12` layout of UUIDs
+	///
+	/// This is the string form of the UUID with the same bits, as given by
+	/// the `From` conversion of the `uuid` feature, without depending on the
+	/// `uuid` crate.
+	///
+	/// # Examples
+	/// ```
+	/// # use ulys::Ulys;
+	/// let ulys = Ulys(0x881a_3bfe_01e9_4438_a68e_b1e7_e82b_7f9c);
+	///
+	/// assert_eq!(ulys.to_hyphenated_string(), "881a3bfe-01e9-4438-a68e-b1e7e82b7f9c");
+	/// ```
+	#[must_use]
+	pub fn to_hyphenated_string(&self) -> String {
+		let value = self.0;
+
+		alloc::format!(
+			"{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+			value >> 96,
+			value >> 80 & 0xffff,
+			value >> 64 & 0xffff,
+			value >> 48 & 0xffff,
+			value & 0xffff_ffff_ffff
+		)
 	}
 
-	#[test]
-	fn test_order() {
-		let dt = SystemTime::now();
-		let ulys1 = Ulys::from_datetime(dt);
-		let ulys2 = Ulys::from_datetime(dt + Duration::from_millis(1));
+	/// Creates a Ulys from a 36-character hyphenated hex string, in the
+	/// `8-4-4-4-12` layout of UUIDs
+	///
+	/// This parses the output of [`Ulys::to_hyphenated_string`], in either
+	/// case. The bits are taken as they are, so the checksum is not verified.
+	///
+	/// # Errors
+	///
+	/// An `UlysError::ParseInvalidLength` will be returned when the string is
+	/// not 36 bytes long, and an `UlysError::InvalidCharAt` when a byte is not
+	/// a hex digit or a hyphen at its expected place.
+	pub fn from_hyphenated_str(s: &str) -> Result<Ulys, UlysError> {
+		const HYPHENATED_LEN: usize = 36;
+		const HYPHENS: [usize; 4] = [8, 13, 18, 23];
 
-		assert!(ulys1 < ulys2);
+		let bytes = s.as_bytes();
+		if bytes.len() != HYPHENATED_LEN {
+			return Err(UlysError::ParseInvalidLength);
+		}
+
+		let mut value: u128 = 0;
+		for (index, &byte) in bytes.iter().enumerate() {
+			let invalid = UlysError::InvalidCharAt { index, byte };
+
+			if HYPHENS.contains(&index) {
+				if byte != b'-' {
+					return Err(invalid);
+				}
+			} else {
+				let digit = char::from(byte).to_digit(16).ok_or(invalid)?;
+				value = value << 4 | u128::from(digit);
+			}
+		}
+
+		Ok(Ulys(value))
 	}
 
-	#[test]
-	fn test_is_valid() {
-		// cSpell:disable-next-line
-		let ulys = Ulys::from_string("068dkwmn3a441g20mzbsmyk5b8").expect("failed to deserialize");
+	/// Gets the datetime of when this Ulys was created accurate to 1ms
+	///
+	/// The timestamp is stored in whole milliseconds, so for any datetime
+	/// `dt` within the range of the timestamp, `Ulys::from_datetime(dt)
+	/// .datetime()` is `dt` floored to the millisecond.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn datetime(&self) -> SystemTime {
+		let stamp = self.timestamp_ms();
+		SystemTime::UNIX_EPOCH + Duration::from_millis(stamp)
+	}
 
-		assert!(ulys.is_valid());
+	/// Gets the datetime of when this Ulys was created, or `None` for the nil
+	/// Ulys
+	///
+	/// The nil Ulys is a sentinel rather than an ID created at the Unix epoch,
+	/// so this keeps it out of time-based analytics, where [`Ulys::datetime`]
+	/// would report it as created in 1970.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn created_at(&self) -> Option<SystemTime> {
+		(!self.is_default()).then(|| self.datetime())
 	}
 
-	#[test]
-	fn test_is_not_valid() {
-		// cSpell:disable-next-line
-		let ulys = Ulys::from_string("068dkwmn3a441g20mzbsmy0000").expect("failed to deserialize");
+	/// Gets the datetime of when a Ulys created by
+	/// [`Ulys::from_datetime_with_epoch`] with the same `epoch` was created
+	/// accurate to 1ms
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn datetime_with_epoch(&self, epoch: SystemTime) -> SystemTime {
+		epoch + Duration::from_millis(self.timestamp_ms())
+	}
 
-		assert!(!ulys.is_valid());
+	/// Gets the signed difference in milliseconds between the timestamps of
+	/// this Ulys and `other`
+	///
+	/// The result is negative when `other` was created after this Ulys.
+	#[must_use]
+	#[allow(clippy::cast_possible_wrap)] // timestamps only use 48 bits
+	pub fn timestamp_delta_ms(&self, other: &Ulys) -> i64 {
+		self.timestamp_ms() as i64 - other.timestamp_ms() as i64
+	}
+
+	/// Increments the random portion of this Ulys, keeping the checksum valid
+	///
+	/// The timestamp is left unchanged and the checksum is recomputed, so the
+	/// result is the next valid Ulys of the same millisecond, as issued by
+	/// [`Generator`]. Returns `None` when the random portion is already at its
+	/// maximum.
+	#[must_use]
+	pub fn increment(&self) -> Option<Ulys> {
+		let random = self.random();
+		if random == Self::RAND_MAX {
+			return None;
+		}
+
+		Some(Self::from_parts(self.timestamp_ms(), random + 1))
+	}
+
+	/// Returns the greater of this Ulys and `other`
+	///
+	/// IDs are ordered by timestamp first, so this is the more recent one,
+	/// with the random portion breaking ties within a millisecond.
+	#[must_use]
+	pub fn newer(self, other: Ulys) -> Ulys {
+		self.max(other)
+	}
+
+	/// Returns the lesser of this Ulys and `other`
+	///
+	/// See [`Ulys::newer`] for the ordering.
+	#[must_use]
+	pub fn older(self, other: Ulys) -> Ulys {
+		self.min(other)
+	}
+
+	/// Returns the Ulys with the later timestamp, or this one when both share
+	/// the same millisecond
+	#[must_use]
+	pub fn newer_by_time(self, other: Ulys) -> Ulys {
+		if other.timestamp_ms() > self.timestamp_ms() {
+			other
+		} else {
+			self
+		}
+	}
+
+	/// Returns the Ulys with the earlier timestamp, or this one when both
+	/// share the same millisecond
+	#[must_use]
+	pub fn older_by_time(self, other: Ulys) -> Ulys {
+		if other.timestamp_ms() < self.timestamp_ms() {
+			other
+		} else {
+			self
+		}
+	}
+
+	/// Checks if the Ulys is valid
+	///
+	/// The checksum covers every bit outside of the checksum field itself: the
+	/// 48 timestamp bits and the 48 random bits.
+	#[must_use]
+	pub fn is_valid(&self) -> bool {
+		self.verify().is_ok()
+	}
+
+	/// Checks if the Ulys is valid, describing the mismatch when it is not
+	///
+	/// This is [`Ulys::is_valid`] for callers that need to report why an ID
+	/// was rejected, e.g. in an API error response.
+	///
+	/// # Errors
+	///
+	/// A `ChecksumError` holding the stored and expected checksums will be
+	/// returned when they differ.
+	pub fn verify(&self) -> Result<(), ChecksumError> {
+		let stored = self.checksum();
+		let expected = self.expected_checksum();
+
+		if stored == expected {
+			Ok(())
+		} else {
+			Err(ChecksumError { stored, expected })
+		}
+	}
+
+	/// Checks if the Ulys is a real, usable ID
+	///
+	/// On top of the checksum verified by [`Ulys::is_valid`], this requires a
+	/// nonzero timestamp and a nonzero random portion. Such nil-like IDs can
+	/// carry a valid checksum (e.g. built with [`Ulys::from_parts`]), but a
+	/// zero random portion has a 2^-48 chance of being generated, so they are
+	/// far more likely to be placeholders than real IDs.
+	#[must_use]
+	pub fn is_well_formed(&self) -> bool {
+		self.is_valid() && !self.is_default() && self.timestamp_ms() != 0 && self.random() != 0
+	}
+
+	/// Gets the 48-bit random portion of this Ulys
+	///
+	/// Two IDs created in the same millisecond with the same random portion
+	/// point to a weak random source, so this is also a way to audit the
+	/// entropy of generated IDs.
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)] // masked to 48 bits
+	pub fn random(&self) -> u64 {
+		((self.0 >> Self::CHECK_BITS) & u128::from(Self::RAND_MAX)) as u64
+	}
+
+	/// Gets the checksum stored in this Ulys
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)] // the checksum is the lowest 32 bits
+	pub fn checksum(&self) -> u32 {
+		self.0 as u32
+	}
+
+	/// Computes the checksum this Ulys should store, given its timestamp and
+	/// random portions
+	///
+	/// When [`Ulys::is_valid`] fails, comparing this with [`Ulys::checksum`]
+	/// helps diagnose how an ID got corrupted.
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)] // shifted to the top 32 bits
+	pub fn expected_checksum(&self) -> u32 {
+		let data = (self.0 >> Self::CHECK_BITS) << Self::CHECK_BITS;
+
+		(Ulys::compute_checksum(data) >> Self::CHECK_BITS) as u32
+	}
+
+	/// Hashes this Ulys with XXH3 under the given seed
+	///
+	/// Each seed gives an independent hash function over the 16 big-endian
+	/// bytes, as needed by count-min sketches, Bloom filters and other
+	/// probabilistic structures. The result is stable across platforms and
+	/// releases, unlike the `Hash` implementation.
+	#[must_use]
+	pub fn hash_with_seed(&self, seed: u64) -> u64 {
+		xxh3_64_with_seed(&self.to_bytes(), seed)
+	}
+
+	/// Checks if the Ulys is valid under the keyed checksum created by
+	/// [`Ulys::new_keyed`] or [`Generator::with_key`] with the same `key`
+	#[must_use]
+	pub fn is_valid_keyed(&self, key: &[u8; 32]) -> bool {
+		*self == self.with_keyed_checksum(key)
+	}
+
+	/// Encodes this Ulys as a string whose lexical order matches the order of
+	/// the values
+	///
+	/// The string is always the 26-character lowercase encoding, the same as
+	/// `Display`. Since the lowercase Crockford alphabet is in ASCII order
+	/// and the length is fixed, comparing these strings byte by byte gives
+	/// the same result as comparing the Ulys themselves. Strings in other
+	/// cases parse to the same Ulys but break this guarantee, since an
+	/// uppercase `Z` sorts before a lowercase `a`: store this form, or
+	/// normalize input with [`Ulys::canonicalize_string`], when sorting by
+	/// string.
+	#[must_use]
+	pub fn to_sortable_string(&self) -> String {
+		self.encode_to_array()
+			.iter()
+			.copied()
+			.map(char::from)
+			.collect()
+	}
+
+	/// Encodes the timestamp and random portions of this Ulys, without the
+	/// checksum
+	///
+	/// The 96 data bits take 20 characters instead of 26, the last one holding
+	/// the lowest random bit followed by 4 zero padding bits. This trades the
+	/// integrity check on the wire for size: the checksum is recomputed by
+	/// [`Ulys::from_checksumless_string`], which therefore cannot detect
+	/// corrupted input. The checksum of this Ulys is not encoded, so an
+	/// invalid Ulys comes back valid.
+	#[must_use]
+	pub fn to_checksumless_string(&self) -> String {
+		let data = (self.0 >> Self::CHECK_BITS) << Self::CHECK_BITS;
+
+		base32::encode(data)[..Self::CHECKSUMLESS_LEN]
+			.iter()
+			.copied()
+			.map(char::from)
+			.collect()
+	}
+
+	/// Gets the first 10 characters of the string encoding, shown as the
+	/// timestamp component by ULID tooling
+	///
+	/// Unlike a ULID, which pads its 48-bit timestamp with 2 leading zero bits
+	/// so that these 10 characters hold the timestamp alone, a Ulys pads at
+	/// the end of the string: the 50 bits of this prefix are the 48 timestamp
+	/// bits followed by the top 2 random bits. Prefixes of IDs from the same
+	/// millisecond can thus differ in their last character.
+	#[must_use]
+	pub fn time_component_str(&self) -> String {
+		self.encode_to_array()[..10]
+			.iter()
+			.copied()
+			.map(char::from)
+			.collect()
+	}
+
+	/// Gets the last 16 characters of the string encoding, shown as the
+	/// random component by ULID tooling
+	///
+	/// Unlike a ULID, where these 80 bits are all random, the 80 bits of a
+	/// Ulys suffix are the lower 46 random bits, the 32 checksum bits and 2
+	/// zero padding bits. See [`Ulys::time_component_str`] for the prefix.
+	#[must_use]
+	pub fn rand_component_str(&self) -> String {
+		self.encode_to_array()[10..]
+			.iter()
+			.copied()
+			.map(char::from)
+			.collect()
+	}
+
+	/// Gets the range of characters of the string encoding that the checksum
+	/// influences
+	///
+	/// Each character encodes 5 bits, read from the most significant end. The
+	/// 96 bits of timestamp and random data fill characters 0 to 18 and the
+	/// top bit of character 19 (96 = 19 * 5 + 1), so the 32 checksum bits
+	/// start 1 bit into character 19 and run through the 3 data bits of the
+	/// last character, whose remaining 2 bits are padding. Character 19 thus
+	/// mixes the lowest random bit with the top 4 checksum bits.
+	#[must_use]
+	pub const fn checksum_char_range() -> Range<usize> {
+		let data_bits = (Self::TIME_BITS + Self::RAND_BITS) as usize;
+
+		data_bits / 5..Self::ULYS_LEN
+	}
+
+	/// Creates the nil Ulys, same as [`Ulys::NIL`]
+	#[must_use]
+	pub const fn nil() -> Ulys {
+		Self::NIL
+	}
+
+	/// Test if the Ulys is nil
+	#[must_use]
+	pub fn is_default(&self) -> bool {
+		self.0 == 0u128
+	}
+
+	/// Encodes a batch of Ulys into a single string, separated by `sep`
+	///
+	/// The output is allocated once for the whole batch and each Ulys is
+	/// encoded directly into it, which avoids the intermediate `String` per
+	/// ID of `ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(sep)`.
+	#[must_use]
+	pub fn join(ids: &[Ulys], sep: &str) -> String {
+		let capacity = ids.len() * Self::ULYS_LEN + sep.len() * ids.len().saturating_sub(1);
+		let mut joined = String::with_capacity(capacity);
+
+		for (index, ulys) in ids.iter().enumerate() {
+			if index > 0 {
+				joined.push_str(sep);
+			}
+			joined.extend(ulys.encode_to_array().map(char::from));
+		}
+
+		joined
+	}
+
+	/// Counts the IDs of a batch that do not pass [`Ulys::is_valid`]
+	#[must_use]
+	pub fn count_invalid(ids: &[Ulys]) -> usize {
+		ids.iter().filter(|ulys| !ulys.is_valid()).count()
+	}
+
+	/// Finds the first Ulys whose timestamp is earlier than the one before it
+	///
+	/// IDs sharing a timestamp are not reported, since only the timestamp is
+	/// ordered for IDs that were not produced by a [`Generator`]. This is
+	/// meant to diagnose append-only logs that should be sorted by creation
+	/// time.
+	#[must_use]
+	pub fn find_ordering_violation(ids: &[Ulys]) -> Option<OrderingViolation> {
+		ids.windows(2).enumerate().find_map(|(index, pair)| {
+			let (prev, curr) = (pair[0], pair[1]);
+			let delta_ms = curr.timestamp_delta_ms(&prev);
+
+			(delta_ms < 0).then_some(OrderingViolation {
+				index: index + 1,
+				prev,
+				curr,
+				delta_ms,
+			})
+		})
+	}
+
+	/// Estimates the probability that at least two of `ids_per_ms` IDs
+	/// created with [`Ulys::new`] within the same millisecond collide
+	///
+	/// IDs from the same millisecond only differ by their 48 random bits, so
+	/// the birthday approximation `1 - exp(-n(n - 1) / 2^49)` applies. The
+	/// probability stays negligible up to millions of IDs per millisecond
+	/// (about 2e-3 at 2^20) but reaches 39% at 2^24: past that volume, use
+	/// a [`Generator`], which never repeats within a millisecond.
+	#[cfg(feature = "std")]
+	#[must_use]
+	#[allow(clippy::cast_precision_loss)] // an approximation anyway
+	pub fn birthday_collision_probability(ids_per_ms: u64) -> f64 {
+		let n = ids_per_ms as f64;
+		let space = (1u64 << Self::RAND_BITS) as f64;
+
+		-(-n * (n - 1.0).max(0.0) / (2.0 * space)).exp_m1()
+	}
+
+	/// Creates a new Ulys with the given datetime floored to `granularity`
+	///
+	/// Coarsening the embedded timestamp (e.g. to the hour) keeps the exact
+	/// creation time from leaking through the identifier, while the random
+	/// portion keeps its full entropy. The tradeoff is ordering: IDs created
+	/// within the same bucket share a timestamp and are no longer ordered by
+	/// creation time relative to each other, only relative to other buckets.
+	///
+	/// A zero `granularity` keeps the full millisecond precision.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn from_datetime_rounded(datetime: SystemTime, granularity: Duration) -> Self {
+		let timestamp = Self::unix_millis(datetime);
+		let granularity = u64::try_from(granularity.as_millis()).map_or(u64::MAX, |ms| ms.max(1));
+
+		Self::from_timestamp(timestamp - timestamp % granularity)
+	}
+
+	/// Creates a new Ulys with the given datetime, in the legacy layout that
+	/// stores the timestamp in the low bits
+	///
+	/// Some legacy systems swap the two portions: the 48 random bits come
+	/// first and the 48 timestamp bits follow, right before the checksum,
+	/// which is computed as usual so the result passes [`Ulys::is_valid`].
+	/// Read the timestamp back with [`Ulys::timestamp_ms_low`].
+	///
+	/// These IDs are NOT sortable: they are ordered by their random portion,
+	/// and every other method reading the timestamp, such as
+	/// [`Ulys::datetime`], reports the random bits instead. Only use this
+	/// layout to interoperate with such systems.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn from_datetime_low_time(datetime: SystemTime) -> Self {
+		let timestamp = Self::unix_millis(datetime);
+
+		Self::from_parts(rand::rng().random(), timestamp)
+	}
+
+	/// Gets the timestamp of a Ulys created by [`Ulys::from_datetime_low_time`]
+	///
+	/// This reads the 48 bits following the top 48 bits, so it is
+	/// meaningless for IDs in the regular layout.
+	#[must_use]
+	pub fn timestamp_ms_low(&self) -> u64 {
+		self.random()
+	}
+
+	/// Creates a new Ulys with the given datetime, storing the milliseconds
+	/// elapsed since `epoch` instead of since the Unix epoch
+	///
+	/// A recent project-specific epoch leaves more of the 48-bit range ahead,
+	/// and keeps the timestamps small early on. Datetimes before `epoch` are
+	/// stored as `epoch` itself. Read the datetime back with
+	/// [`Ulys::datetime_with_epoch`] and the same `epoch`: every other method
+	/// reading the timestamp, such as [`Ulys::datetime`], assumes the Unix
+	/// epoch.
+	///
+	/// IDs only sort by time against IDs using the same epoch, so mixing
+	/// epochs within a dataset breaks its time ordering.
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn from_datetime_with_epoch(datetime: SystemTime, epoch: SystemTime) -> Self {
+		Self::from_timestamp(Self::millis_since(datetime, epoch))
+	}
+
+	/// Creates a new Ulys with the given datetime
+	///
+	/// The timestamp is clamped to the representable range: datetimes before
+	/// the Unix epoch are stored as the epoch itself, and datetimes past the
+	/// 48-bit millisecond range (around the year 10889) are stored as the
+	/// largest timestamp. No ordering is enforced between calls, so a clock
+	/// stepping backwards produces a Ulys that sorts before the previous one.
+	#[cfg(feature = "std")]
+	fn from_datetime(datetime: SystemTime) -> Self {
+		let timestamp = Self::unix_millis(datetime);
+
+		Self::from_timestamp(timestamp)
+	}
+
+	/// Creates a new Ulys with the given datetime whose random portion is not
+	/// weak, see [`Ulys::new_nonweak`]
+	#[cfg(feature = "std")]
+	fn from_datetime_nonweak_with_source<R: Rng + ?Sized>(
+		datetime: SystemTime,
+		min_weight: u32,
+		source: &mut R,
+	) -> Self {
+		let timestamp = Self::unix_millis(datetime);
+		let min_weight = min_weight.min(u32::from(Self::RAND_BITS) / 2);
+
+		loop {
+			let ulys = Self::from_timestamp_with_source(timestamp, source);
+			let random = ulys.random();
+
+			if random != 0 && random.count_ones() >= min_weight {
+				return ulys;
+			}
+		}
+	}
+
+	/// Creates a new Ulys with the given datetime that is not in `seen`, see
+	/// [`Ulys::new_checked_unique`]
+	#[cfg(feature = "std")]
+	fn from_datetime_checked_unique_with_source<R: Rng + ?Sized>(
+		datetime: SystemTime,
+		seen: &mut HashSet<Ulys>,
+		source: &mut R,
+	) -> Self {
+		let timestamp = Self::unix_millis(datetime);
+
+		loop {
+			let ulys = Self::from_timestamp_with_source(timestamp, source);
+
+			if seen.insert(ulys) {
+				return ulys;
+			}
+		}
+	}
+
+	/// Gets the milliseconds elapsed since the Unix epoch, clamped to the
+	/// range of the timestamp portion
+	#[cfg(feature = "std")]
+	fn unix_millis(datetime: SystemTime) -> u64 {
+		Self::millis_since(datetime, SystemTime::UNIX_EPOCH)
+	}
+
+	/// Gets the milliseconds elapsed since `epoch`, clamped to the range of
+	/// the timestamp portion
+	#[cfg(feature = "std")]
+	fn millis_since(datetime: SystemTime, epoch: SystemTime) -> u64 {
+		let millis = datetime
+			.duration_since(epoch)
+			.unwrap_or(Duration::ZERO)
+			.as_millis();
+
+		u64::try_from(millis).map_or(Self::TIME_MAX, |millis| millis.min(Self::TIME_MAX))
+	}
+
+	/// Creates a new Ulys with the given timestamp in milliseconds
+	#[cfg(feature = "std")]
+	fn from_timestamp(timestamp: u64) -> Self {
+		Self::from_timestamp_with_source(timestamp, &mut rand::rng())
+	}
+
+	/// Creates a new Ulys with the given timestamp in milliseconds and random
+	/// source
+	#[cfg(feature = "std")]
+	fn from_timestamp_with_source<R: Rng + ?Sized>(timestamp: u64, source: &mut R) -> Self {
+		// A single draw covers the 48 random bits, the top 16 are discarded.
+		let random = source.random::<u64>() & Self::RAND_MAX;
+
+		Self::from_parts(timestamp, random)
+	}
+
+	/// Creates a checksum for the given data
+	///
+	/// `data` is the full Ulys with its checksum bits (the lowest 32) zeroed,
+	/// so the hash covers the timestamp and the whole random portion.
+	fn compute_checksum(data: u128) -> u64 {
+		xxh3_64(data.to_be_bytes().as_slice())
+	}
+
+	/// Replaces the checksum of this Ulys by the keyed checksum for `key`
+	fn with_keyed_checksum(self, key: &[u8; 32]) -> Self {
+		let data = (self.0 >> Self::CHECK_BITS) << Self::CHECK_BITS;
+		let mut input = [0; 48];
+		input[..32].copy_from_slice(key);
+		input[32..].copy_from_slice(&data.to_be_bytes());
+		let checksum = xxh3_64(&input);
+
+		Self(data | u128::from(checksum >> Self::CHECK_BITS))
+	}
+
+	/// Gets the timestamp section of this Ulys
+	fn timestamp_ms(&self) -> u64 {
+		(self.0 >> (Self::RAND_BITS + Self::CHECK_BITS)) as u64
+	}
+}
+
+/// Derives a seed for [`Ulys::new_fast`] without reading OS entropy
+#[cfg(feature = "std")]
+fn fast_seed() -> u64 {
+	let mut hasher = DefaultHasher::new();
+	SystemTime::now().hash(&mut hasher);
+	std::process::id().hash(&mut hasher);
+	std::thread::current().id().hash(&mut hasher);
+	hasher.finish()
+}
+
+impl fmt::Debug for Ulys {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Ulys")
+			.field("str", &self.to_string())
+			.field("timestamp_ms", &self.timestamp_ms())
+			.field("random", &self.random())
+			.field("valid", &self.is_valid())
+			.finish()
+	}
+}
+
+/// Formats a Ulys as its lowercase Crockford Base32 string, or in uppercase
+/// with the alternate flag (`{:#}`) for systems expecting the ULID casing.
+impl fmt::Display for Ulys {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Encoding into an array avoids allocating, so `to_string` allocates
+		// the 26 bytes of the result only.
+		let mut encoded = self.encode_to_array();
+		if f.alternate() {
+			encoded.make_ascii_uppercase();
+		}
+
+		f.write_str(core::str::from_utf8(&encoded).map_err(|_| fmt::Error)?)
+	}
+}
+
+impl FromStr for Ulys {
+	type Err = UlysError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ulys::from_string(s)
+	}
+}
+
+impl From<&[u8; 16]> for Ulys {
+	/// Reads a Ulys from big-endian bytes borrowed from a larger buffer
+	fn from(bytes: &[u8; 16]) -> Self {
+		Ulys(u128::from_be_bytes(*bytes))
+	}
+}
+
+impl TryFrom<&[u8]> for Ulys {
+	type Error = UlysError;
+
+	/// Reads a Ulys from a slice of exactly 16 big-endian bytes
+	fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+		let bytes: &[u8; 16] = bytes.try_into().map_err(|_| UlysError::ParseToArray)?;
+		Ok(Ulys::from(bytes))
+	}
+}
+
+impl TryFrom<Vec<u8>> for Ulys {
+	type Error = UlysError;
+
+	/// Reads a Ulys from exactly 16 big-endian bytes
+	fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+		Ulys::try_from(bytes.as_slice())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use core::convert::Infallible;
+	use rand::TryRng;
+	use std::collections::VecDeque;
+
+	/// A random source replaying a fixed sequence of values
+	struct SequenceRng(VecDeque<u64>);
+
+	impl SequenceRng {
+		fn new(values: &[u64]) -> Self {
+			SequenceRng(values.iter().copied().collect())
+		}
+	}
+
+	impl TryRng for SequenceRng {
+		type Error = Infallible;
+
+		#[allow(clippy::cast_possible_truncation)]
+		fn try_next_u32(&mut self) -> Result<u32, Infallible> {
+			self.try_next_u64().map(|value| value as u32)
+		}
+
+		fn try_next_u64(&mut self) -> Result<u64, Infallible> {
+			Ok(self.0.pop_front().expect("random sequence exhausted"))
+		}
+
+		fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Infallible> {
+			for chunk in dst.chunks_mut(8) {
+				let bytes = self.try_next_u64()?.to_le_bytes();
+				chunk.copy_from_slice(&bytes[..chunk.len()]);
+			}
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn test_display_alternate_uppercase() {
+		let ulys = Ulys::from_parts(1_700_000_000_000, 0x1234_5678_9abc);
+
+		// cSpell:disable
+		assert_eq!(format!("{ulys}"), "065wzsb800938nkrkay14zwe7r");
+		assert_eq!(format!("{ulys:#}"), "065WZSB800938NKRKAY14ZWE7R");
+		// cSpell:enable
+		assert_eq!(Ulys::from_string(&format!("{ulys:#}")), Ok(ulys));
+	}
+
+	#[test]
+	fn test_display_matches_base32_crate() {
+		for _ in 0..1000 {
+			let ulys = Ulys(rand::random());
+			let expected = ::base32::encode(::base32::Alphabet::Crockford, &ulys.0.to_be_bytes());
+
+			assert_eq!(ulys.to_string(), expected.to_lowercase());
+			assert_eq!(format!("{ulys:#}"), expected);
+		}
+	}
+
+	#[test]
+	fn test_is_default() {
+		let ulys = Ulys::new();
+		assert!(!ulys.is_default());
+
+		let nil = Ulys::default();
+		assert!(nil.is_default());
+
+		assert_eq!(nil.to_string(), "00000000000000000000000000");
+		assert_eq!(Ulys::nil(), nil);
+		assert_eq!(Ulys::NIL, nil);
+		assert!(!Ulys::NIL.is_valid());
+	}
+
+	#[test]
+	fn test_max() {
+		assert_eq!(Ulys::MAX.to_string(), "zzzzzzzzzzzzzzzzzzzzzzzzzw");
+		assert!(!Ulys::MAX.is_valid());
+		assert!(Ulys::new() < Ulys::MAX);
+		assert!(Ulys::NIL < Ulys::new());
+	}
+
+	#[test]
+	fn test_from_string() {
+		// cSpell:disable-next-line
+		let text = "068cbxpc1wy9d0v9gbhrg0020r";
+		let ulys = Ulys::from_string(text);
+
+		assert!(ulys.is_ok());
+
+		let data = ulys.expect("failed to deserialize");
+		assert_eq!(data.to_string(), text);
+		assert_eq!(data.0, 2_080_933_931_387_190_948_831_204_449_898_725_894);
+	}
+
+	#[test]
+	fn test_from_string_invalid_length() {
+		let ulys = Ulys::from_string("ABC");
+
+		assert!(ulys.is_err());
+		assert_eq!(ulys.unwrap_err(), UlysError::ParseInvalidLength);
+	}
+
+	#[test]
+	fn test_from_string_invalid_letter() {
+		let ulys = Ulys::from_string("0000000000000u000000000000");
+
+		assert!(ulys.is_err());
+		assert_eq!(
+			ulys.unwrap_err(),
+			UlysError::InvalidCharAt {
+				index: 13,
+				byte: b'u'
+			}
+		);
+	}
+
+	#[test]
+	fn test_dynamic() {
+		let ulys = Ulys::new();
+		let encoded = ulys.to_string();
+		let ulys2 = Ulys::from_string(&encoded).expect("failed to deserialize");
+
+		assert_eq!(ulys, ulys2);
+	}
+
+	#[test]
+	fn test_datetime() {
+		let dt = SystemTime::now();
+		let ulys = Ulys::from_datetime(dt);
+
+		assert!(ulys.datetime() <= dt);
+		assert!(ulys.datetime() + Duration::from_millis(1) >= dt);
+	}
+
+	#[test]
+	fn test_datetime_floors_to_millisecond() {
+		let mut source = rand::rng();
+
+		for _ in 0..10_000 {
+			let millis = source.random_range(0..=Ulys::TIME_MAX);
+			let nanos = source.random_range(0..1_000_000);
+			let dt = SystemTime::UNIX_EPOCH
+				+ Duration::from_millis(millis)
+				+ Duration::from_nanos(nanos);
+
+			assert_eq!(
+				Ulys::from_datetime(dt).datetime(),
+				SystemTime::UNIX_EPOCH + Duration::from_millis(millis)
+			);
+		}
+	}
+
+	#[test]
+	fn test_timestamp() {
+		let dt = SystemTime::now();
+		let ulys = Ulys::from_datetime(dt);
+		let ts = dt
+			.duration_since(SystemTime::UNIX_EPOCH)
+			.unwrap()
+			.as_millis();
+
+		assert_eq!(u128::from(ulys.timestamp_ms()), ts);
+	}
+
+	#[test]
+	fn test_order() {
+		let dt = SystemTime::now();
+		let ulys1 = Ulys::from_datetime(dt);
+		let ulys2 = Ulys::from_datetime(dt + Duration::from_millis(1));
+
+		assert!(ulys1 < ulys2);
+	}
+
+	#[test]
+	fn test_is_valid() {
+		// cSpell:disable-next-line
+		let ulys = Ulys::from_string("068dkwmn3a441g20mzbsmyk5b8").expect("failed to deserialize");
+
+		assert!(ulys.is_valid());
+	}
+
+	#[test]
+	fn test_is_not_valid() {
+		// cSpell:disable-next-line
+		let ulys = Ulys::from_string("068dkwmn3a441g20mzbsmy0000").expect("failed to deserialize");
+
+		assert!(!ulys.is_valid());
+	}
+
+	#[test]
+	fn test_from_byte_array_ref() {
+		let ulys = Ulys::new();
+		let mut record = [0u8; 20];
+		record[2..18].copy_from_slice(&ulys.0.to_be_bytes());
+
+		let bytes: &[u8; 16] = record[2..18].try_into().expect("slice of 16 bytes");
+		let borrowed = Ulys::from(bytes);
+		let owned = Ulys(u128::from_be_bytes(*bytes));
+
+		assert_eq!(borrowed, owned);
+		assert_eq!(borrowed, ulys);
+	}
+
+	#[test]
+	fn test_debug() {
+		// cSpell:disable-next-line
+		let ulys = Ulys::from_string("068dkwmn3a441g20mzbsmyk5b8").expect("failed to deserialize");
+		let debug = format!("{ulys:?}");
+
+		// cSpell:disable-next-line
+		assert!(debug.contains(r#"str: "068dkwmn3a441g20mzbsmyk5b8""#));
+		assert!(debug.contains(&format!("timestamp_ms: {}", ulys.timestamp_ms())));
+		assert!(debug.contains("valid: true"));
+
+		let broken = Ulys(ulys.0 ^ 1);
+		assert!(format!("{broken:?}").contains("valid: false"));
+	}
+
+	#[test]
+	fn test_from_datetime_rounded() {
+		let hour = Duration::from_hours(1);
+		let start = SystemTime::UNIX_EPOCH + Duration::from_hours(472_222);
+		let ulys1 = Ulys::from_datetime_rounded(start + Duration::from_mins(1), hour);
+		let ulys2 = Ulys::from_datetime_rounded(start + Duration::from_mins(59), hour);
+
+		assert_eq!(ulys1.timestamp_ms(), ulys2.timestamp_ms());
+		assert_eq!(ulys1.datetime(), start);
+		assert!(ulys1.is_valid());
+
+		let ulys3 = Ulys::from_datetime_rounded(start + hour, hour);
+		assert!(ulys1 < ulys3);
+	}
+
+	#[test]
+	fn test_from_datetime_with_epoch() {
+		let epoch = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+		let dt = epoch + Duration::from_millis(123_456);
+		let ulys = Ulys::from_datetime_with_epoch(dt, epoch);
+
+		assert_eq!(ulys.timestamp_ms(), 123_456);
+		assert_eq!(ulys.datetime_with_epoch(epoch), dt);
+		assert!(ulys.is_valid());
+		assert!(ulys < Ulys::from_datetime_with_epoch(dt + Duration::from_millis(1), epoch));
+
+		// Datetimes before the epoch are clamped to it.
+		let early = Ulys::from_datetime_with_epoch(epoch - Duration::from_secs(1), epoch);
+		assert_eq!(early.datetime_with_epoch(epoch), epoch);
+
+		// The Unix epoch matches the default constructors.
+		let unix = Ulys::from_datetime_with_epoch(dt, SystemTime::UNIX_EPOCH);
+		assert_eq!(unix.timestamp_ms(), Ulys::unix_millis(dt));
+		assert_eq!(
+			unix.datetime_with_epoch(SystemTime::UNIX_EPOCH),
+			unix.datetime()
+		);
+	}
+
+	#[test]
+	fn test_from_datetime_low_time() {
+		let dt = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+		let ulys = Ulys::from_datetime_low_time(dt);
+
+		assert_eq!(ulys.timestamp_ms_low(), 1_700_000_000_123);
+		assert!(ulys.is_valid());
+		assert_eq!(Ulys::from_string(&ulys.to_string()), Ok(ulys));
+		assert_eq!(
+			Ulys::from_bytes(ulys.to_bytes()).timestamp_ms_low(),
+			1_700_000_000_123
+		);
+
+		// The random portion comes first, so the IDs do not sort by time.
+		assert_ne!(ulys.timestamp_ms(), 1_700_000_000_123);
+		assert_eq!(
+			ulys.0 >> Ulys::CHECK_BITS & u128::from(Ulys::TIME_MAX),
+			1_700_000_000_123
+		);
+	}
+
+	#[test]
+	fn test_from_datetime_rounded_zero_granularity() {
+		let dt = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+		let ulys = Ulys::from_datetime_rounded(dt, Duration::ZERO);
+
+		assert_eq!(ulys.timestamp_ms(), 1_700_000_000_123);
+	}
+
+	#[test]
+	fn test_from_string_rejects_ambiguous_letters() {
+		// cSpell:disable
+		for (text, index) in [
+			("068cbxpc1wy9d0v9gbhrgo020r", 21),
+			("068cbxpc1wy9d0v9gbhrgO020r", 21),
+			("068cbxpcIwy9d0v9gbhrg0020r", 8),
+			("068cbxpclwy9d0v9gbhrg0020r", 8),
+			("068cbxpcLwy9d0v9gbhrg0020r", 8),
+		] {
+			let byte = text.as_bytes()[index];
+			assert_eq!(
+				Ulys::from_string(text),
+				Err(UlysError::InvalidCharAt { index, byte })
+			);
+		}
+		// cSpell:enable
+	}
+
+	#[test]
+	fn test_new_batch() {
+		let ids = Ulys::new_batch(1000);
+
+		assert_eq!(ids.len(), 1000);
+		assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+		assert!(ids.iter().all(Ulys::is_valid));
+		assert!(Ulys::new_batch(0).is_empty());
+	}
+
+	#[test]
+	fn test_hyphenated_string() {
+		// Same layout as `Uuid::from(ulys).to_string()`.
+		// cSpell:disable
+		let ulys = Ulys::from_string("h0d3qzg1x523h9mep7kygavzkg").unwrap();
+		// cSpell:enable
+		let text = "881a3bfe-01e9-4438-a68e-b1e7e82b7f9c";
+
+		assert_eq!(ulys.to_hyphenated_string(), text);
+		assert_eq!(Ulys::from_hyphenated_str(text), Ok(ulys));
+		assert_eq!(Ulys::from_hyphenated_str(&text.to_uppercase()), Ok(ulys));
+		assert_eq!(
+			Ulys::default().to_hyphenated_string(),
+			"00000000-0000-0000-0000-000000000000"
+		);
+
+		for _ in 0..100 {
+			let ulys = Ulys::new();
+			assert_eq!(
+				Ulys::from_hyphenated_str(&ulys.to_hyphenated_string()),
+				Ok(ulys)
+			);
+		}
+	}
+
+	#[test]
+	fn test_from_hyphenated_str_invalid() {
+		assert_eq!(
+			Ulys::from_hyphenated_str("881a3bfe01e94438a68eb1e7e82b7f9c"),
+			Err(UlysError::ParseInvalidLength)
+		);
+		assert_eq!(
+			Ulys::from_hyphenated_str("881a3bfe-01e9-4438-a68e-b1e7e82b7f9g"),
+			Err(UlysError::InvalidCharAt {
+				index: 35,
+				byte: b'g'
+			})
+		);
+		assert_eq!(
+			Ulys::from_hyphenated_str("881a3bfe-01e9-4438-a68eb-1e7e82b7f9c"),
+			Err(UlysError::InvalidCharAt {
+				index: 23,
+				byte: b'b'
+			})
+		);
+		assert_eq!(
+			Ulys::from_hyphenated_str("881a3bfe-01e9-4438-a68e-b1e7e82b-f9c"),
+			Err(UlysError::InvalidCharAt {
+				index: 32,
+				byte: b'-'
+			})
+		);
+	}
+
+	#[test]
+	fn test_from_ascii_bytes() {
+		// cSpell:disable
+		let expected = Ulys::from_string("068cbxpc1wy9d0v9gbhrg0020r").unwrap();
+
+		assert_eq!(
+			Ulys::from_ascii_bytes(b"068cbxpc1wy9d0v9gbhrg0020r"),
+			Ok(expected)
+		);
+		assert_eq!(
+			Ulys::from_ascii_bytes(b"068CBXPC1WY9D0V9GBHRG0020R"),
+			Ok(expected)
+		);
+		assert_eq!(
+			Ulys::from_ascii_bytes(b"068cbxpc1wy9d0v9gbhrg0020"),
+			Err(UlysError::ParseInvalidLength)
+		);
+		assert_eq!(
+			Ulys::from_ascii_bytes(b"068cbxpc1wy9d0v9gbhrg\xff020r"),
+			Err(UlysError::InvalidCharAt {
+				index: 21,
+				byte: 0xff
+			})
+		);
+		// cSpell:enable
+	}
+
+	#[test]
+	fn test_from_string_trimmed() {
+		// cSpell:disable
+		let expected = Ulys::from_string("068cbxpc1wy9d0v9gbhrg0020r").unwrap();
+
+		for text in [
+			"068cbxpc1wy9d0v9gbhrg0020r\r",
+			"068cbxpc1wy9d0v9gbhrg0020r\r\n",
+			"  068cbxpc1wy9d0v9gbhrg0020r ",
+			"\t\x00068cbxpc1wy9d0v9gbhrg0020r\x1b",
+		] {
+			assert_eq!(Ulys::from_string_trimmed(text), Ok(expected), "{text:?}");
+		}
+
+		assert_eq!(
+			Ulys::from_string_trimmed("068cbxpc1wy9d\r0v9gbhrg0020r"),
+			Err(UlysError::ParseInvalidLength)
+		);
+		assert_eq!(
+			Ulys::from_string_trimmed(" 068cbxpc1wy9d 0v9gbhrg0020 "),
+			Err(UlysError::InvalidCharAt {
+				index: 13,
+				byte: b' '
+			})
+		);
+		// cSpell:enable
+	}
+
+	#[test]
+	fn test_from_string_crockford_lenient() {
+		// cSpell:disable
+		let expected = Ulys::from_string("068cbxpc1wy9d0v9gbhrg0020r").unwrap();
+
+		for text in [
+			"068cbxpc1wy9d0v9gbhrgo020r",
+			"068cbxpc1wy9d0v9gbhrgO020r",
+			"068cbxpcIwy9d0v9gbhrg0020r",
+			"068cbxpciwy9d0v9gbhrg0020r",
+			"068cbxpclwy9d0v9gbhrg0020r",
+			"068CBXPCLWY9D0V9GBHRGO020R",
+		] {
+			assert_eq!(Ulys::from_string_crockford_lenient(text), Ok(expected));
+		}
+
+		assert_eq!(
+			Ulys::from_string_crockford_lenient("068cbxpc1wy9d0v9gbhrgu020r"),
+			Err(UlysError::InvalidCharAt {
+				index: 21,
+				byte: b'u'
+			})
+		);
+		// cSpell:enable
+		assert_eq!(
+			Ulys::from_string_crockford_lenient("ABC"),
+			Err(UlysError::ParseInvalidLength)
+		);
+	}
+
+	#[test]
+	fn test_checksum_covers_timestamp_and_random() {
+		// cSpell:disable-next-line
+		let ulys = Ulys::from_string("068dkwmn3a441g20mzbsmyk5b8").expect("failed to deserialize");
+		assert!(ulys.is_valid());
+
+		for bit in Ulys::CHECK_BITS..128 {
+			let flipped = Ulys(ulys.0 ^ (1 << bit));
+			assert!(!flipped.is_valid(), "flipping bit {bit} was not detected");
+		}
+	}
+
+	#[test]
+	fn test_count_invalid() {
+		let valid = Ulys::from_parts(1_700_000_000_000, 0x1234_5678_9abc);
+		let invalid = Ulys(valid.0 ^ 1);
+
+		assert_eq!(Ulys::count_invalid(&[]), 0);
+		assert_eq!(Ulys::count_invalid(&[valid, invalid, valid, Ulys::MAX]), 2);
+	}
+
+	#[test]
+	fn test_verify() {
+		let ulys = Ulys::from_parts(1_700_000_000_000, 0x1234_5678_9abc);
+		assert_eq!(ulys.verify(), Ok(()));
+
+		let corrupted = Ulys(ulys.0 ^ 1);
+		let err = corrupted.verify().unwrap_err();
+		assert_eq!(err.stored, ulys.checksum() ^ 1);
+		assert_eq!(err.expected, ulys.checksum());
+		assert_eq!(
+			err.to_string(),
+			format!(
+				"checksum mismatch (expected {:#010x}, got {:#010x})",
+				ulys.checksum(),
+				ulys.checksum() ^ 1
+			)
+		);
+		assert!(!corrupted.is_valid());
+	}
+
+	#[test]
+	fn test_checksum_covers_low_random_bits() {
+		let ulys = Ulys::new();
+		let low_random = ((1u128 << 32) - 1) << Ulys::CHECK_BITS;
+
+		assert!(ulys.is_valid());
+		assert!(!Ulys(ulys.0 ^ low_random).is_valid());
+	}
+
+	#[test]
+	fn test_nonweak_rejects_zero() {
+		let dt = SystemTime::now();
+		// The bits above the random portion are discarded, so the first
+		// draw is zero.
+		let mut source = SequenceRng::new(&[0xffff << 48, 0xbeef_1234_5678]);
+		let ulys = Ulys::from_datetime_nonweak_with_source(dt, 0, &mut source);
+
+		assert_eq!(ulys.random(), 0xbeef_1234_5678);
+		assert!(ulys.is_valid());
+		assert!(source.0.is_empty());
+	}
+
+	#[test]
+	fn test_nonweak_rejects_low_weight() {
+		let dt = SystemTime::now();
+		let mut source = SequenceRng::new(&[1, 0xffff_ffff_ffff]);
+		let ulys = Ulys::from_datetime_nonweak_with_source(dt, 8, &mut source);
+
+		assert_eq!(ulys.random(), 0xffff_ffff_ffff);
+
+		let mut source = SequenceRng::new(&[0x0f0f_0f0f_0f0f]);
+		let ulys = Ulys::from_datetime_nonweak_with_source(dt, 64, &mut source);
+		assert_eq!(ulys.random().count_ones(), 24);
+	}
+
+	#[test]
+	fn test_random_portion_is_uniform() {
+		let mut source = rand::rng();
+		let mut set_bits = [0u32; Ulys::RAND_BITS as usize];
+		let draws = 10_000;
+
+		for _ in 0..draws {
+			let random = Ulys::from_timestamp_with_source(0, &mut source).random();
+			for (bit, count) in set_bits.iter_mut().enumerate() {
+				*count += u32::from(random >> bit & 1 == 1);
+			}
+		}
+
+		// Each bit is set with probability 1/2: 10,000 draws land within
+		// 4,700..5,300 with a margin of 6 standard deviations.
+		for (bit, count) in set_bits.iter().enumerate() {
+			assert!(
+				(4_700..5_300).contains(count),
+				"bit {bit} set {count} times"
+			);
+		}
+	}
+
+	#[test]
+	fn test_new_nonweak() {
+		let ulys = Ulys::new_nonweak(16);
+
+		assert!(ulys.is_valid());
+		assert!(ulys.random().count_ones() >= 16);
+	}
+
+	#[test]
+	fn test_from_datetime_at_epoch() {
+		let ulys = Ulys::from_datetime(SystemTime::UNIX_EPOCH);
+
+		assert_eq!(ulys.timestamp_ms(), 0);
+		assert_eq!(ulys.datetime(), SystemTime::UNIX_EPOCH);
+		assert!(ulys.is_valid());
+	}
+
+	#[test]
+	fn test_from_datetime_before_epoch() {
+		let dt = SystemTime::UNIX_EPOCH - Duration::from_micros(1);
+		let ulys = Ulys::from_datetime(dt);
+
+		assert_eq!(ulys.timestamp_ms(), 0);
+		assert_eq!(ulys.datetime(), SystemTime::UNIX_EPOCH);
+		assert!(ulys.is_valid());
+	}
+
+	#[test]
+	fn test_from_datetime_beyond_range() {
+		let dt = SystemTime::UNIX_EPOCH + Duration::from_millis(1 << 50);
+		let ulys = Ulys::from_datetime(dt);
+
+		assert_eq!(ulys.timestamp_ms(), (1 << Ulys::TIME_BITS) - 1);
+		assert!(ulys.datetime() < dt);
+		assert!(ulys.is_valid());
+	}
+
+	#[test]
+	fn test_from_datetime_non_monotonic() {
+		let dt = SystemTime::now();
+		let ulys1 = Ulys::from_datetime(dt);
+		let ulys2 = Ulys::from_datetime(dt - Duration::from_secs(1));
+
+		assert_eq!(ulys1.timestamp_ms() - ulys2.timestamp_ms(), 1000);
+		assert!(ulys2 < ulys1);
+		assert!(ulys2.is_valid());
+	}
+
+	#[test]
+	fn test_encode_matches_display() {
+		for ulys in [Ulys(0), Ulys(u128::MAX), Ulys(1), Ulys::new(), Ulys::new()] {
+			assert_eq!(
+				ulys.encode_to_array()
+					.map(char::from)
+					.iter()
+					.collect::<String>(),
+				ulys.to_string()
+			);
+		}
+	}
+
+	#[test]
+	fn test_join() {
+		let ids = [Ulys::new(), Ulys::new(), Ulys::default(), Ulys(u128::MAX)];
+
+		for sep in ["", "\n", ", "] {
+			let expected = ids
+				.iter()
+				.map(ToString::to_string)
+				.collect::<Vec<_>>()
+				.join(sep);
+			let joined = Ulys::join(&ids, sep);
+
+			assert_eq!(joined, expected);
+			assert_eq!(joined.capacity(), expected.len());
+		}
+
+		assert_eq!(Ulys::join(&[], ","), "");
+		assert_eq!(Ulys::join(&ids[..1], ","), ids[0].to_string());
+	}
+
+	#[test]
+	fn test_timestamp_delta_ms() {
+		let dt = SystemTime::now();
+		let ulys1 = Ulys::from_datetime(dt);
+		let ulys2 = Ulys::from_datetime(dt + Duration::from_millis(1500));
+		let ulys3 = Ulys::from_datetime(dt);
+
+		assert_eq!(ulys2.timestamp_delta_ms(&ulys1), 1500);
+		assert_eq!(ulys1.timestamp_delta_ms(&ulys2), -1500);
+		assert_eq!(ulys1.timestamp_delta_ms(&ulys3), 0);
+
+		let max = Ulys(u128::MAX);
+		let nil = Ulys::default();
+		assert_eq!(max.timestamp_delta_ms(&nil), (1 << Ulys::TIME_BITS) - 1);
+		assert_eq!(nil.timestamp_delta_ms(&max), 1 - (1 << Ulys::TIME_BITS));
+	}
+
+	#[test]
+	fn test_keyed_checksum() {
+		let key = [7; 32];
+		let other_key = [8; 32];
+		let ulys = Ulys::new_keyed(&key);
+
+		assert!(ulys.is_valid_keyed(&key));
+		assert!(!ulys.is_valid_keyed(&other_key));
+		assert!(!ulys.is_valid());
+		assert!(!Ulys(ulys.0 ^ (1 << 40)).is_valid_keyed(&key));
+		assert!(!Ulys::new().is_valid_keyed(&key));
+	}
+
+	#[test]
+	fn test_new_fast() {
+		let ids: Vec<Ulys> = (0..1000).map(|_| Ulys::new_fast()).collect();
+		let unique: std::collections::HashSet<_> = ids.iter().collect();
+
+		assert_eq!(unique.len(), ids.len());
+		assert!(ids.iter().all(Ulys::is_valid));
+	}
+
+	#[test]
+	fn test_parse_any() {
+		// cSpell:disable-next-line
+		let expected = Ulys::from_string("068cbxpc1wy9d0v9gbhrg0020r").unwrap();
+
+		// cSpell:disable-next-line
+		assert_eq!(Ulys::parse_any("068CBXPC1WY9D0V9GBHRG0020R"), Ok(expected));
+		assert_eq!(
+			Ulys::parse_any(&format!("{:032x}", expected.0)),
+			Ok(expected)
+		);
+		assert_eq!(
+			Ulys::parse_any(&format!("{:032X}", expected.0)),
+			Ok(expected)
+		);
+		assert_eq!(Ulys::parse_any(&expected.0.to_string()), Ok(expected));
+		assert_eq!(Ulys::parse_any("0"), Ok(Ulys::default()));
+		assert_eq!(Ulys::parse_any(&u128::MAX.to_string()), Ok(Ulys(u128::MAX)));
+	}
+
+	#[cfg(feature = "uuid")]
+	#[test]
+	fn test_parse_any_uuid() {
+		// cSpell:disable-next-line
+		let expected = Ulys::from_string("h0d3qzg1x523h9mep7kygavzkg").unwrap();
+
+		assert_eq!(
+			Ulys::parse_any("881a3bfe-01e9-4438-a68e-b1e7e82b7f9c"),
+			Ok(expected)
+		);
+		assert_eq!(
+			Ulys::parse_any("881a3bfe-01e9-4438-a68e-b1e7e82b7f9g"),
+			Err(UlysError::InvalidUuid)
+		);
+	}
+
+	#[test]
+	fn test_parse_any_invalid() {
+		assert_eq!(Ulys::parse_any(""), Err(UlysError::ParseInvalidLength));
+		assert_eq!(
+			Ulys::parse_any("not an id"),
+			Err(UlysError::ParseInvalidLength)
+		);
+		assert_eq!(
+			Ulys::parse_any("+1234567890abcdef1234567890abcde"),
+			Err(UlysError::ParseInvalidLength)
+		);
+		assert_eq!(
+			Ulys::parse_any("0000000000000000000000000000000g"),
+			Err(UlysError::ParseInvalidLength)
+		);
+		assert_eq!(
+			Ulys::parse_any("340282366920938463463374607431768211456"),
+			Err(UlysError::ValueOverflow)
+		);
+		assert_eq!(
+			Ulys::parse_any("0000000000000u000000000000"),
+			Err(UlysError::InvalidCharAt {
+				index: 13,
+				byte: b'u'
+			})
+		);
+	}
+
+	#[test]
+	fn test_canonicalize_string() {
+		let canonical = "h0d3qzg1x523h9mep7kygavzkg";
+
+		assert_eq!(
+			Ulys::canonicalize_string("H0D3QZG1X523H9MEP7KYGAVZKG").as_deref(),
+			Ok(canonical)
+		);
+		assert_eq!(
+			Ulys::canonicalize_string("h0D3qZg1X523h9MeP7kYgAvZkG").as_deref(),
+			Ok(canonical)
+		);
+		assert_eq!(
+			Ulys::canonicalize_string(canonical).as_deref(),
+			Ok(canonical)
+		);
+		assert_eq!(
+			Ulys::canonicalize_string("h0d3qzg1x523"),
+			Err(UlysError::ParseInvalidLength)
+		);
+	}
+
+	#[test]
+	fn test_find_ordering_violation() {
+		let ids: Vec<Ulys> = [10, 10, 20, 35].map(Ulys::from_timestamp).to_vec();
+		assert_eq!(Ulys::find_ordering_violation(&ids), None);
+		assert_eq!(Ulys::find_ordering_violation(&[]), None);
+
+		let ids: Vec<Ulys> = [10, 20, 35, 12, 5].map(Ulys::from_timestamp).to_vec();
+		assert_eq!(
+			Ulys::find_ordering_violation(&ids),
+			Some(OrderingViolation {
+				index: 3,
+				prev: ids[2],
+				curr: ids[3],
+				delta_ms: -23,
+			})
+		);
+	}
+
+	#[test]
+	fn test_const_decode_encode() {
+		// cSpell:disable-next-line
+		const ULYS: Result<Ulys, UlysError> = Ulys::decode("065WZSB800938NKRKAY14ZWE7R");
+		const NIL: [u8; Ulys::ULYS_LEN] = Ulys::NIL.encode_to_array();
+
+		let ulys = ULYS.unwrap();
+		assert_eq!(ulys, Ulys::from_parts(1_700_000_000_000, 0x1234_5678_9abc));
+		assert_eq!(ulys.encode_to_array(), *ulys.to_string().as_bytes());
+		assert_eq!(&NIL, b"00000000000000000000000000");
+		assert_eq!(Ulys::decode("065wzsb8"), Err(UlysError::ParseInvalidLength));
+	}
+
+	#[test]
+	fn test_increment() {
+		let ulys = Ulys::from_parts(1_700_000_000_000, 0xbeef);
+		let next = ulys.increment().unwrap();
+
+		assert!(next > ulys);
+		assert!(next.is_valid());
+		assert_eq!(next.timestamp_ms(), ulys.timestamp_ms());
+		assert_eq!(next.random(), 0xbef0);
+
+		let last = Ulys::from_parts(1_700_000_000_000, Ulys::RAND_MAX);
+		assert_eq!(last.increment(), None);
+	}
+
+	#[test]
+	fn test_random() {
+		let ulys = Ulys::from_parts(1_700_000_000_000, 0xbeef_1234_5678);
+		assert_eq!(ulys.random(), 0xbeef_1234_5678);
+
+		assert_eq!(Ulys::from_parts(0, Ulys::RAND_MAX).random(), Ulys::RAND_MAX);
+		assert_eq!(Ulys::from_parts(u64::MAX, 0).random(), 0);
+	}
+
+	#[test]
+	fn test_checksum_char_range() {
+		let range = Ulys::checksum_char_range();
+		assert_eq!(range, 19..26);
+
+		let original = Ulys(u128::MAX);
+		let encoded = original.to_string();
+
+		for index in 0..Ulys::ULYS_LEN {
+			let mut zeroed = encoded.clone().into_bytes();
+			zeroed[index] = b'0';
+			let zeroed = Ulys::from_string(core::str::from_utf8(&zeroed).unwrap()).unwrap();
+
+			assert_eq!(
+				zeroed.checksum() != original.checksum(),
+				range.contains(&index),
+				"character {index}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_with_shard() {
+		let mut source = rand::rng();
+
+		for shard in [0, 1, 0x1234, u16::MAX] {
+			let ulys = Ulys::with_shard(shard, &mut source);
+			assert_eq!(ulys.shard(), shard);
+			assert!(ulys.is_valid());
+		}
+
+		let mut source = SequenceRng::new(&[0xdead_beef]);
+		let a = Ulys::with_shard(0x00ff, &mut source);
+		let mut source = SequenceRng::new(&[0xdead_beef]);
+		let b = Ulys::with_shard(0xff00, &mut source);
+
+		// Only the 16 shard bits differ between the random portions.
+		assert_eq!(a.random() ^ b.random(), 0xffff << 32);
+		assert_eq!(a.random() & 0xffff_ffff, 0xdead_beef);
+	}
+
+	#[test]
+	fn test_newer_older() {
+		let early = Ulys::from_parts(10, 500);
+		let late = Ulys::from_parts(20, 1);
+
+		assert_eq!(early.newer(late), late);
+		assert_eq!(late.newer(early), late);
+		assert_eq!(early.older(late), early);
+		assert_eq!(late.older(early), early);
+		assert_eq!(early.newer(early), early);
+		assert_eq!(early.older(early), early);
+
+		let same_ms = Ulys::from_parts(10, 600);
+		assert_eq!(early.newer(same_ms), same_ms);
+		assert_eq!(same_ms.older(early), early);
+	}
+
+	#[test]
+	fn test_newer_older_by_time() {
+		let early = Ulys::from_parts(10, 500);
+		let late = Ulys::from_parts(20, 1);
+		let same_ms = Ulys::from_parts(10, 600);
+
+		assert_eq!(early.newer_by_time(late), late);
+		assert_eq!(late.newer_by_time(early), late);
+		assert_eq!(early.older_by_time(late), early);
+		assert_eq!(late.older_by_time(early), early);
+
+		// Ties within a millisecond keep the receiver.
+		assert_eq!(early.newer_by_time(same_ms), early);
+		assert_eq!(same_ms.newer_by_time(early), same_ms);
+		assert_eq!(early.older_by_time(same_ms), early);
+		assert_eq!(same_ms.older_by_time(early), same_ms);
+	}
+
+	#[test]
+	fn test_new_global_monotonic() {
+		let handles: Vec<_> = (0..8)
+			.map(|_| {
+				std::thread::spawn(|| {
+					(0..1000)
+						.map(|_| Ulys::new_global_monotonic())
+						.collect::<Vec<_>>()
+				})
+			})
+			.collect();
+
+		let mut all = HashSet::new();
+		for handle in handles {
+			let ids = handle.join().unwrap();
+			assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+			assert!(ids.iter().all(Ulys::is_valid));
+			all.extend(ids);
+		}
+		assert_eq!(all.len(), 8000);
+	}
+
+	#[test]
+	fn test_hash_matches_inner_u128() {
+		use std::hash::BuildHasher;
+
+		let state = std::collections::hash_map::RandomState::new();
+		for ulys in [Ulys::default(), Ulys::new(), Ulys(u128::MAX)] {
+			assert_eq!(state.hash_one(ulys), state.hash_one(ulys.0));
+		}
+	}
+
+	#[test]
+	fn test_from_seed() {
+		let order = Ulys::from_seed("order-1");
+
+		assert_eq!(order, Ulys::from_seed("order-1"));
+		assert_ne!(order, Ulys::from_seed("order-2"));
+		assert_ne!(order, Ulys::from_seed(""));
+		assert!(order.is_valid());
+		assert!(Ulys::from_seed("").is_valid());
+		// The derivation must stay stable for golden tests.
+		assert_eq!(order.to_string(), "ba5415gkz45d1bv3m3w47s7ccw");
+	}
+
+	#[test]
+	fn test_hash_with_seed() {
+		let ulys = Ulys::from_seed("order-1");
+
+		assert_eq!(ulys.hash_with_seed(1), ulys.hash_with_seed(1));
+		assert_ne!(ulys.hash_with_seed(1), ulys.hash_with_seed(2));
+		assert_ne!(ulys.hash_with_seed(0), ulys.hash_with_seed(u64::MAX));
+		assert_ne!(
+			ulys.hash_with_seed(1),
+			Ulys::from_seed("order-2").hash_with_seed(1)
+		);
+	}
+
+	#[test]
+	fn test_component_strs() {
+		let ulys = Ulys::new();
+		let time = ulys.time_component_str();
+		let rand = ulys.rand_component_str();
+
+		assert_eq!(time.len(), 10);
+		assert_eq!(rand.len(), 16);
+		assert_eq!(time + &rand, ulys.to_string());
+
+		// Same millisecond, top 2 random bits clear: only the random component
+		// differs.
+		let a = Ulys::from_parts(1_700_000_000_000, 1);
+		let b = Ulys::from_parts(1_700_000_000_000, 2);
+		assert_eq!(a.time_component_str(), b.time_component_str());
+		assert_ne!(a.rand_component_str(), b.rand_component_str());
+	}
+
+	#[test]
+	fn test_bit_layout() {
+		let layout = Ulys::bit_layout();
+
+		assert_eq!(
+			u32::from(layout.time_bits)
+				+ u32::from(layout.rand_bits)
+				+ u32::from(layout.check_bits),
+			128
+		);
+		assert_eq!(layout.time_offset, layout.rand_offset + layout.rand_bits);
+		assert_eq!(layout.rand_offset, layout.check_offset + layout.check_bits);
+		assert_eq!(layout.time_offset + layout.time_bits, 128);
+
+		let field = |ulys: Ulys, offset: u8, bits: u8| (ulys.0 >> offset) & ((1 << bits) - 1);
+		let ulys = Ulys::new();
+		assert_eq!(
+			field(ulys, layout.time_offset, layout.time_bits),
+			u128::from(ulys.timestamp_ms())
+		);
+		assert_eq!(
+			field(ulys, layout.rand_offset, layout.rand_bits),
+			u128::from(ulys.random())
+		);
+		assert_eq!(
+			field(ulys, layout.check_offset, layout.check_bits),
+			u128::from(ulys.checksum())
+		);
+	}
+
+	#[test]
+	fn test_to_sortable_string() {
+		let mut generator = Generator::new();
+		let mut ids: Vec<Ulys> = (0..1000).map(|_| generator.generate().unwrap()).collect();
+		ids.extend((0..1000).map(|_| Ulys::new()));
+		ids.extend([Ulys::default(), Ulys(u128::MAX)]);
+
+		let mut strings: Vec<String> = ids.iter().map(Ulys::to_sortable_string).collect();
+		strings.sort();
+		ids.sort();
+
+		assert_eq!(
+			strings,
+			ids.iter().map(ToString::to_string).collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn test_birthday_collision_probability() {
+		assert!(Ulys::birthday_collision_probability(0).abs() < f64::EPSILON);
+		assert!(Ulys::birthday_collision_probability(1).abs() < f64::EPSILON);
+
+		let low = Ulys::birthday_collision_probability(1000);
+		assert!(low > 0.0 && low < 1e-8);
+
+		let high = Ulys::birthday_collision_probability(1 << 24);
+		assert!((high - 0.393).abs() < 1e-3);
+
+		assert!((Ulys::birthday_collision_probability(1 << 30) - 1.0).abs() < 1e-9);
+	}
+
+	#[test]
+	fn test_invalid_char_at_display() {
+		let err = Ulys::from_string("068cbxpc1wy9d0v9gbhrgu020r").unwrap_err();
+		assert_eq!(err.to_string(), "invalid character 'u' at index 21");
+
+		let err = Ulys::from_string("068cbxpc1wy9d0v9gbhrg002é").unwrap_err();
+		assert_eq!(err.to_string(), "invalid character '\\xc3' at index 24");
+	}
+
+	#[test]
+	fn test_stress_test_uniqueness() {
+		assert!(Ulys::stress_test_uniqueness(100_000));
+	}
+
+	#[test]
+	fn test_new_checked_unique() {
+		let mut seen = HashSet::new();
+		let first = Ulys::new_checked_unique(&mut seen);
+		let second = Ulys::new_checked_unique(&mut seen);
+
+		assert_ne!(first, second);
+		assert_eq!(seen.len(), 2);
+	}
+
+	#[test]
+	fn test_checked_unique_regenerates_on_collision() {
+		let datetime = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+		let mut seen = HashSet::new();
+
+		// The source repeats its first draw, which must be rejected.
+		let mut source =
+			SequenceRng::new(&[0x07 << 32 | 0x2a, 0x07 << 32 | 0x2a, 0x08 << 32 | 0x2b]);
+		let first =
+			Ulys::from_datetime_checked_unique_with_source(datetime, &mut seen, &mut source);
+		let second =
+			Ulys::from_datetime_checked_unique_with_source(datetime, &mut seen, &mut source);
+
+		assert_eq!(first.random(), 0x07 << 32 | 0x2a);
+		assert_eq!(second.random(), 0x08 << 32 | 0x2b);
+		assert_eq!(seen, HashSet::from([first, second]));
+		assert!(source.0.is_empty());
+	}
+
+	#[test]
+	fn test_checksumless_string() {
+		for ulys in [
+			Ulys::new(),
+			Ulys::from_parts(0, 0),
+			Ulys::from_parts(u64::MAX, u64::MAX),
+		] {
+			let compact = ulys.to_checksumless_string();
+
+			assert_eq!(compact.len(), 20);
+			assert!(ulys.to_string().starts_with(&compact[..19]));
+			assert_eq!(Ulys::from_checksumless_string(&compact), Ok(ulys));
+		}
+
+		let invalid = Ulys(Ulys::new().0 ^ 1);
+		let rebuilt = Ulys::from_checksumless_string(&invalid.to_checksumless_string()).unwrap();
+		assert!(rebuilt.is_valid());
+		assert_eq!(rebuilt.random(), invalid.random());
+
+		assert_eq!(
+			Ulys::from_checksumless_string("068cbxpc1wy9d0v9gbhrg0020r"),
+			Err(UlysError::ParseInvalidLength)
+		);
+		assert_eq!(
+			Ulys::from_checksumless_string("068cbxpc1wy9d0v9gbhu"),
+			Err(UlysError::InvalidCharAt {
+				index: 19,
+				byte: b'u'
+			})
+		);
+	}
+
+	#[test]
+	fn test_try_from_byte_slice() {
+		let ulys = Ulys::new();
+		let bytes = ulys.0.to_be_bytes();
+
+		assert_eq!(Ulys::try_from(&bytes[..]), Ok(ulys));
+		assert_eq!(Ulys::try_from(bytes.to_vec()), Ok(ulys));
+		assert_eq!(Ulys::try_from(&bytes[..15]), Err(UlysError::ParseToArray));
+		assert_eq!(Ulys::try_from(vec![0; 17]), Err(UlysError::ParseToArray));
+		assert_eq!(Ulys::try_from(&[][..]), Err(UlysError::ParseToArray));
+	}
+
+	#[test]
+	fn test_created_at() {
+		assert_eq!(Ulys::default().created_at(), None);
+
+		let ulys = Ulys::new();
+		assert_eq!(ulys.created_at(), Some(ulys.datetime()));
+
+		// Only the nil value is a sentinel, not every ID at the epoch.
+		let epoch = Ulys::from_parts(0, 1);
+		assert_eq!(epoch.created_at(), Some(SystemTime::UNIX_EPOCH));
+	}
+
+	#[test]
+	fn test_checksum_accessors() {
+		// cSpell:disable-next-line
+		let ulys = Ulys::from_string("068dkwmn3a441g20mzbsmyk5b8").unwrap();
+		assert_eq!(ulys.checksum(), 0x9a7a_655a);
+		assert_eq!(ulys.expected_checksum(), 0x9a7a_655a);
+
+		let corrupted = Ulys(ulys.0 ^ 1 << 40);
+		assert!(!corrupted.is_valid());
+		assert_eq!(corrupted.checksum(), 0x9a7a_655a);
+		assert_ne!(corrupted.expected_checksum(), corrupted.checksum());
+		assert_eq!(
+			Ulys(corrupted.0 & !0xffff_ffff | u128::from(corrupted.expected_checksum())),
+			Ulys::from_parts(corrupted.timestamp_ms(), corrupted.random())
+		);
+	}
+
+	#[test]
+	fn test_proto_pair() {
+		let ulys = Ulys::new();
+		let (high, low) = ulys.to_proto_pair();
+
+		assert_eq!(Ulys::from_proto_pair(high, low), ulys);
+
+		let bytes = ulys.0.to_be_bytes();
+		assert_eq!(high.to_be_bytes(), bytes[..8]);
+		assert_eq!(low.to_be_bytes(), bytes[8..]);
+
+		assert_eq!(Ulys::from_proto_pair(0, 0), Ulys::default());
+		assert_eq!(Ulys::from_proto_pair(u64::MAX, u64::MAX), Ulys(u128::MAX));
+		assert_eq!(Ulys::from_proto_pair(1, 0), Ulys(1 << 64));
+	}
+
+	#[test]
+	fn test_from_parts_truncates() {
+		let ulys = Ulys::from_parts(1_700_000_000_000, 0x1234_5678_9abc);
+		let truncated = Ulys::from_parts(
+			0x018b_cfe5_6800 | 0xffff << 48,
+			0x1234_5678_9abc | 0xabcd << 48,
+		);
+
+		assert_eq!(truncated, ulys);
+		assert!(truncated.is_valid());
+		assert_eq!(ulys.timestamp_ms(), 1_700_000_000_000);
+		assert_eq!(ulys.random(), 0x1234_5678_9abc);
+	}
+
+	#[test]
+	fn test_bytes() {
+		let ulys = Ulys::new();
+
+		assert_eq!(ulys.to_bytes(), ulys.0.to_be_bytes());
+		assert_eq!(Ulys::from_bytes(ulys.to_bytes()), ulys);
+		assert_eq!(Ulys::try_from(&ulys.to_bytes()[..]), Ok(ulys));
+
+		let mut ids: Vec<Ulys> = (0..100).map(|_| Ulys::new()).collect();
+		ids.extend([Ulys::default(), Ulys(u128::MAX), Ulys(1 << 64)]);
+		let mut keys: Vec<[u8; 16]> = ids.iter().map(Ulys::to_bytes).collect();
+		ids.sort();
+		keys.sort_unstable();
+		assert_eq!(
+			keys.into_iter().map(Ulys::from_bytes).collect::<Vec<_>>(),
+			ids
+		);
+	}
+
+	#[test]
+	fn test_is_well_formed() {
+		let ulys = Ulys::new();
+		assert!(ulys.is_well_formed());
+
+		assert!(!Ulys::default().is_well_formed());
+		assert!(!Ulys(ulys.0 ^ 1).is_well_formed());
+		assert!(!Ulys::from_parts(0, 1).is_well_formed());
+		assert!(!Ulys::from_parts(1, 0).is_well_formed());
+		assert!(Ulys::from_parts(0, 1).is_valid());
+	}
+
+	#[test]
+	fn test_error_trait() {
+		fn parse(s: &str) -> Result<Ulys, Box<dyn std::error::Error>> {
+			Ok(Ulys::from_string(s)?)
+		}
+
+		assert!(parse(&Ulys::new().to_string()).is_ok());
+		assert_eq!(parse("ABC").unwrap_err().to_string(), "invalid length");
+
+		let err: Box<dyn std::error::Error + Send + Sync> = MonotonicError::Overflow.into();
+		assert_eq!(err.to_string(), "ulys random bits would overflow");
 	}
 }
@@ -0,0 +1,80 @@
+//! Ulys identifiers that are known not to be nil.
+
+use crate::Ulys;
+use core::fmt;
+use core::ops::Deref;
+
+/// A Ulys that is guaranteed not to be the nil (all zero) value.
+///
+/// Like [`core::num::NonZeroU128`], the invariant is checked once at
+/// construction so fields of this type never need an `is_default()` check.
+///
+/// # Examples
+/// ```
+/// # use ulys::{NonNilUlys, Ulys};
+/// assert!(NonNilUlys::new(Ulys::default()).is_none());
+///
+/// let id = NonNilUlys::new(Ulys::new()).unwrap();
+/// assert!(!id.is_default());
+/// ```
+#[derive(Debug, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct NonNilUlys(Ulys);
+
+impl NonNilUlys {
+	/// Creates a non-nil Ulys, or `None` if the given Ulys is nil
+	#[must_use]
+	pub const fn new(ulys: Ulys) -> Option<Self> {
+		if ulys.0 == 0 {
+			None
+		} else {
+			Some(NonNilUlys(ulys))
+		}
+	}
+
+	/// Gets the wrapped Ulys
+	#[must_use]
+	pub const fn get(self) -> Ulys {
+		self.0
+	}
+}
+
+impl Deref for NonNilUlys {
+	type Target = Ulys;
+
+	fn deref(&self) -> &Ulys {
+		&self.0
+	}
+}
+
+impl From<NonNilUlys> for Ulys {
+	fn from(non_nil: NonNilUlys) -> Self {
+		non_nil.0
+	}
+}
+
+impl fmt::Display for NonNilUlys {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.0, f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn non_nil_rejects_nil() {
+		assert_eq!(NonNilUlys::new(Ulys::default()), None);
+	}
+
+	#[test]
+	fn non_nil_accepts_other_values() {
+		let ulys = Ulys::new();
+		let non_nil = NonNilUlys::new(ulys).unwrap();
+
+		assert_eq!(non_nil.get(), ulys);
+		assert_eq!(*non_nil, ulys);
+		assert_eq!(non_nil.to_string(), ulys.to_string());
+		assert!(NonNilUlys::new(Ulys(1)).is_some());
+	}
+}
@@ -1,4 +1,10 @@
-use crate::Ulys;
+//! `ToSql` and `FromSql` from `postgres-types` for ULYSes.
+//!
+//! `postgres` and `tokio-postgres` share these traits, so the `postgres`
+//! feature covers both the sync and the async clients. A Ulys is bound as a
+//! `uuid` and can be stored in or read from a `bytea` column too.
+
+use crate::{Ulys, UlysError};
 use bytes::BufMut;
 use bytes::BytesMut;
 use postgres_types::accepts;
@@ -6,6 +12,22 @@ use postgres_types::to_sql_checked;
 use postgres_types::{FromSql, IsNull, ToSql, Type};
 use std::error::Error;
 
+impl Ulys {
+	/// Creates a Ulys from the payload of a `NOTIFY` notification
+	///
+	/// Payloads are plain text, so surrounding whitespace (e.g. a trailing
+	/// newline added by a trigger) is trimmed before parsing the canonical
+	/// string.
+	///
+	/// # Errors
+	///
+	/// An `UlysError` will be returned when the trimmed payload is not a valid
+	/// Ulys string.
+	pub fn from_notification_payload(payload: &str) -> Result<Ulys, UlysError> {
+		Ulys::from_string(payload.trim())
+	}
+}
+
 impl FromSql<'_> for Ulys {
 	fn from_sql(_ty: &Type, raw: &[u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
 		if raw.len() != 16 {
@@ -15,7 +37,7 @@ impl FromSql<'_> for Ulys {
 		bytes.copy_from_slice(raw);
 		Ok(Ulys(u128::from_be_bytes(bytes)))
 	}
-	accepts!(UUID);
+	accepts!(UUID, BYTEA);
 }
 
 impl ToSql for Ulys {
@@ -25,7 +47,7 @@ impl ToSql for Ulys {
 		Ok(IsNull::No)
 	}
 
-	accepts!(UUID);
+	accepts!(UUID, BYTEA);
 	to_sql_checked!();
 }
 
@@ -51,4 +73,65 @@ mod tests {
 
 		assert_eq!(ulys, Ulys::from_sql(t, &bs).unwrap());
 	}
+
+	#[test]
+	fn accepts_uuid_and_bytea() {
+		for ty in [Type::UUID, Type::BYTEA] {
+			assert!(<Ulys as ToSql>::accepts(&ty));
+			assert!(<Ulys as FromSql>::accepts(&ty));
+		}
+		assert!(!<Ulys as ToSql>::accepts(&Type::TEXT));
+	}
+
+	#[tokio::test]
+	#[ignore = "needs a Postgres server at ULYS_TEST_POSTGRES_URL"]
+	async fn tokio_postgres_cycle() {
+		let url = std::env::var("ULYS_TEST_POSTGRES_URL").unwrap();
+		let (client, connection) = tokio_postgres::connect(&url, tokio_postgres::NoTls)
+			.await
+			.unwrap();
+		tokio::spawn(connection);
+
+		client
+			.batch_execute("CREATE TEMPORARY TABLE ids (id uuid, raw bytea)")
+			.await
+			.unwrap();
+
+		let ulys = Ulys::new();
+		client
+			.execute("INSERT INTO ids (id, raw) VALUES ($1, $2)", &[&ulys, &ulys])
+			.await
+			.unwrap();
+
+		let row = client
+			.query_one("SELECT id, raw FROM ids", &[])
+			.await
+			.unwrap();
+		assert_eq!(row.get::<_, Ulys>("id"), ulys);
+		assert_eq!(row.get::<_, Ulys>("raw"), ulys);
+
+		let row = client
+			.query_one("SELECT octet_length(raw) FROM ids", &[])
+			.await
+			.unwrap();
+		assert_eq!(row.get::<_, i32>(0), 16);
+	}
+
+	#[test]
+	fn notification_payload() {
+		let ulys = Ulys::new();
+
+		assert_eq!(
+			Ulys::from_notification_payload(&format!(" {ulys}\n")),
+			Ok(ulys)
+		);
+		assert_eq!(
+			Ulys::from_notification_payload("order created"),
+			Err(UlysError::ParseInvalidLength)
+		);
+		assert_eq!(
+			Ulys::from_notification_payload(""),
+			Err(UlysError::ParseInvalidLength)
+		);
+	}
 }
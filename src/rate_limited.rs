@@ -0,0 +1,93 @@
+//! Rate-limited monotonic generation of ULYSes.
+
+use crate::{Generator, Ulys};
+use core::num::NonZeroU32;
+use std::time::Duration;
+use tokio::time::{interval, Interval, MissedTickBehavior};
+
+/// A monotonic Ulys generator emitting at most a given number of IDs per
+/// second.
+///
+/// Each call to [`RateLimitedGenerator::next`] waits for the next tick of a
+/// `tokio` interval before generating, so bursts of requests are smoothed to
+/// the configured rate. This caps throughput, not latency: a single call may
+/// wait up to one period, and callers that fall behind are not allowed to
+/// catch up with a burst.
+///
+/// # Examples
+/// ```
+/// # use core::num::NonZeroU32;
+/// # use ulys::RateLimitedGenerator;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let mut generator = RateLimitedGenerator::new(NonZeroU32::new(1000).unwrap());
+///
+/// let ulys1 = generator.next().await;
+/// let ulys2 = generator.next().await;
+///
+/// assert!(ulys1 < ulys2);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RateLimitedGenerator {
+	generator: Generator,
+	interval: Interval,
+}
+
+impl RateLimitedGenerator {
+	/// Creates a generator emitting at most `per_second` IDs per second
+	///
+	/// # Panics
+	///
+	/// Panics when called outside of a `tokio` runtime.
+	#[must_use]
+	pub fn new(per_second: NonZeroU32) -> Self {
+		let period = (Duration::from_secs(1) / per_second.get()).max(Duration::from_nanos(1));
+		let mut interval = interval(period);
+		interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+		RateLimitedGenerator {
+			generator: Generator::new(),
+			interval,
+		}
+	}
+
+	/// Waits for the next slot and generates a Ulys greater than the
+	/// previous one
+	///
+	/// When the random portion would overflow within the current millisecond,
+	/// the generator waits for further slots until the clock moves on.
+	pub async fn next(&mut self) -> Ulys {
+		loop {
+			self.interval.tick().await;
+			if let Ok(ulys) = self.generator.generate() {
+				return ulys;
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tokio::time::Instant;
+
+	#[tokio::test(start_paused = true)]
+	async fn test_rate_limited_generator() {
+		let mut generator = RateLimitedGenerator::new(NonZeroU32::new(4).unwrap());
+		let start = Instant::now();
+
+		let mut ids = Vec::new();
+		let mut instants = Vec::new();
+		for _ in 0..5 {
+			ids.push(generator.next().await);
+			instants.push(Instant::now() - start);
+		}
+
+		assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+		assert_eq!(
+			instants,
+			[0, 250, 500, 750, 1000].map(Duration::from_millis)
+		);
+	}
+}
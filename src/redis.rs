@@ -0,0 +1,97 @@
+//! Conversions between ULYS and Redis values.
+//!
+//! ULYSes are written as their 26-character canonical string, so keys and
+//! values stay readable with `redis-cli`. They are read from that string or
+//! from their 16 big-endian bytes.
+//!
+//! # Examples
+//! ```no_run
+//! # use redis::Commands;
+//! # use ulys::Ulys;
+//! # fn main() -> redis::RedisResult<()> {
+//! let client = redis::Client::open("redis://127.0.0.1/")?;
+//! let mut con = client.get_connection()?;
+//!
+//! let ulys = Ulys::new();
+//! let parent = Ulys::new();
+//! con.set::<_, _, ()>(ulys, parent)?;
+//!
+//! assert_eq!(con.get::<_, Ulys>(ulys)?, parent);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::Ulys;
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+
+impl ToRedisArgs for Ulys {
+	fn write_redis_args<W>(&self, out: &mut W)
+	where
+		W: ?Sized + RedisWrite,
+	{
+		out.write_arg(&self.encode_to_array());
+	}
+}
+
+impl ToSingleRedisArg for Ulys {}
+
+impl FromRedisValue for Ulys {
+	fn from_redis_value_ref(v: &Value) -> Result<Self, ParsingError> {
+		let bytes = match v {
+			Value::BulkString(bytes) => bytes.as_slice(),
+			Value::SimpleString(text) => text.as_bytes(),
+			_ => return Err(format!("expected a ulys string or 16 bytes, got {v:?}").into()),
+		};
+
+		let ulys = if bytes.len() == 16 {
+			Ulys::try_from(bytes)
+		} else {
+			Ulys::from_ascii_bytes(bytes)
+		};
+		ulys.map_err(|err| err.to_string().into())
+	}
+
+	fn from_redis_value(v: Value) -> Result<Self, ParsingError> {
+		Ulys::from_redis_value_ref(&v)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn redis_cycle() {
+		let ulys = Ulys::new();
+
+		let args = ulys.to_redis_args();
+		assert_eq!(args, [ulys.to_string().into_bytes()]);
+
+		let value = Value::BulkString(args[0].clone());
+		assert_eq!(Ulys::from_redis_value_ref(&value), Ok(ulys));
+		assert_eq!(Ulys::from_redis_value(value), Ok(ulys));
+	}
+
+	#[test]
+	fn redis_binary_and_simple_strings() {
+		let ulys = Ulys::new();
+
+		let binary = Value::BulkString(ulys.to_bytes().to_vec());
+		assert_eq!(Ulys::from_redis_value(binary), Ok(ulys));
+
+		let simple = Value::SimpleString(ulys.to_string());
+		assert_eq!(Ulys::from_redis_value(simple), Ok(ulys));
+	}
+
+	#[test]
+	fn redis_invalid_values() {
+		for value in [
+			Value::Nil,
+			Value::Int(42),
+			Value::BulkString(b"not a ulys".to_vec()),
+			Value::BulkString(vec![0; 15]),
+		] {
+			assert!(Ulys::from_redis_value(value).is_err());
+		}
+	}
+}
@@ -0,0 +1,110 @@
+//! Zero-copy archival of ULYSes with `rkyv`.
+//!
+//! A Ulys is archived as an [`ArchivedUlys`] holding its 16 big-endian
+//! bytes, see [`Ulys::to_bytes`]. Archives are thus the same on every
+//! architecture, and archived IDs compare in the same order as the IDs
+//! themselves, so a sorted archived slice can be binary-searched in place.
+//!
+//! # Examples
+//! ```
+//! # use ulys::Ulys;
+//! # use ulys::rkyv::ArchivedUlys;
+//! let mut ids: Vec<Ulys> = (0..10).map(|_| Ulys::new()).collect();
+//! ids.sort();
+//!
+//! let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&ids).unwrap();
+//! let archived = rkyv::access::<rkyv::Archived<Vec<Ulys>>, rkyv::rancor::Error>(&bytes).unwrap();
+//!
+//! assert_eq!(archived.binary_search(&ArchivedUlys::from(ids[3])), Ok(3));
+//! ```
+
+use crate::Ulys;
+use rkyv::bytecheck::CheckBytes;
+use rkyv::munge::munge;
+use rkyv::rancor::Fallible;
+use rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+
+/// An archived Ulys, stored as its 16 big-endian bytes.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(transparent)]
+pub struct ArchivedUlys([u8; 16]);
+
+impl ArchivedUlys {
+	/// Gets the Ulys without going through a deserializer
+	#[must_use]
+	pub const fn to_ulys(&self) -> Ulys {
+		Ulys::from_bytes(self.0)
+	}
+}
+
+impl From<Ulys> for ArchivedUlys {
+	fn from(ulys: Ulys) -> Self {
+		ArchivedUlys(ulys.to_bytes())
+	}
+}
+
+impl PartialEq<Ulys> for ArchivedUlys {
+	fn eq(&self, other: &Ulys) -> bool {
+		self.0 == other.to_bytes()
+	}
+}
+
+impl Archive for Ulys {
+	type Archived = ArchivedUlys;
+	type Resolver = ();
+
+	fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+		munge!(let ArchivedUlys(bytes) = out);
+		bytes.write(self.to_bytes());
+	}
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Ulys {
+	fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+		Ok(())
+	}
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Ulys, D> for ArchivedUlys {
+	fn deserialize(&self, _: &mut D) -> Result<Ulys, D::Error> {
+		Ok(self.to_ulys())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rkyv::rancor::Error;
+
+	#[test]
+	fn archived_vec_is_ordered() {
+		let mut ids: Vec<Ulys> = (0..100).map(|_| Ulys::new_fast()).collect();
+		ids.extend([Ulys::NIL, Ulys::MAX]);
+		ids.sort_unstable();
+
+		let bytes = rkyv::to_bytes::<Error>(&ids).unwrap();
+		let archived = rkyv::access::<rkyv::Archived<Vec<Ulys>>, Error>(&bytes).unwrap();
+
+		assert_eq!(archived.len(), ids.len());
+		assert!(archived.windows(2).all(|pair| pair[0] < pair[1]));
+		for (index, ulys) in ids.iter().enumerate() {
+			assert_eq!(archived[index], *ulys);
+			assert_eq!(
+				archived.binary_search(&ArchivedUlys::from(*ulys)),
+				Ok(index)
+			);
+		}
+
+		let deserialized: Vec<Ulys> = rkyv::deserialize::<_, Error>(archived).unwrap();
+		assert_eq!(deserialized, ids);
+	}
+
+	#[test]
+	fn archived_is_big_endian() {
+		let ulys = Ulys::from_parts(1_700_000_000_000, 0x1234_5678_9abc);
+		let bytes = rkyv::to_bytes::<Error>(&ulys).unwrap();
+
+		assert_eq!(bytes.as_slice(), ulys.to_bytes());
+	}
+}
@@ -0,0 +1,255 @@
+//! Conversions between ULYS and `SQLite` values through `rusqlite`.
+//!
+//! By default, ULYSes are stored as a 16-byte big-endian `BLOB`, which is
+//! compact and compares in time order. Wrap a value in [`UlysText`] to store
+//! it as its 26-character canonical string in a `TEXT` column instead. Both
+//! forms are accepted when reading, so existing data in either form can be
+//! read as a plain `Ulys`.
+
+use crate::Ulys;
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+impl ToSql for Ulys {
+	fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+		Ok(ToSqlOutput::from(self.to_bytes().to_vec()))
+	}
+}
+
+/// Reads a ULYS from a 16-byte `BLOB` or from a `TEXT` canonical string.
+impl FromSql for Ulys {
+	fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+		match value {
+			ValueRef::Blob(blob) => {
+				let bytes: &[u8; 16] =
+					blob.try_into().map_err(|_| FromSqlError::InvalidBlobSize {
+						expected_size: 16,
+						blob_size: blob.len(),
+					})?;
+				Ok(Ulys::from(bytes))
+			}
+			ValueRef::Text(text) => Ulys::from_ascii_bytes(text)
+				.map_err(|err| FromSqlError::Other(err.to_string().into())),
+			_ => Err(FromSqlError::InvalidType),
+		}
+	}
+}
+
+/// A ULYS stored as its 26-character canonical string in a `TEXT` column.
+///
+/// # Examples
+/// ```
+/// # use ulys::Ulys;
+/// # use ulys::rusqlite::UlysText;
+/// # use rusqlite::{params, Connection};
+/// let conn = Connection::open_in_memory().unwrap();
+/// conn.execute("CREATE TABLE t (id TEXT)", []).unwrap();
+///
+/// let ulys = Ulys::new();
+/// conn.execute("INSERT INTO t (id) VALUES (?1)", params![UlysText(ulys)]).unwrap();
+///
+/// let stored: String = conn
+///     .query_row("SELECT id FROM t", [], |row| row.get(0))
+///     .unwrap();
+/// assert_eq!(stored, ulys.to_string());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct UlysText(pub Ulys);
+
+impl ToSql for UlysText {
+	fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+		Ok(ToSqlOutput::from(self.0.to_string()))
+	}
+}
+
+impl FromSql for UlysText {
+	fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+		Ulys::column_result(value).map(UlysText)
+	}
+}
+
+impl From<Ulys> for UlysText {
+	fn from(ulys: Ulys) -> Self {
+		UlysText(ulys)
+	}
+}
+
+impl From<UlysText> for Ulys {
+	fn from(text: UlysText) -> Self {
+		text.0
+	}
+}
+
+/// A ULYS stored as a 16-byte big-endian `BLOB`.
+///
+/// This is how a plain `Ulys` is stored too, but only `BLOB` values are
+/// accepted when reading.
+///
+/// # Examples
+/// ```
+/// # use ulys::Ulys;
+/// # use ulys::rusqlite::UlysBlob;
+/// # use rusqlite::{params, Connection};
+/// let conn = Connection::open_in_memory().unwrap();
+/// conn.execute("CREATE TABLE t (id BLOB)", []).unwrap();
+///
+/// let ulys = Ulys::new();
+/// conn.execute("INSERT INTO t (id) VALUES (?1)", params![UlysBlob(ulys)]).unwrap();
+///
+/// let UlysBlob(stored) = conn
+///     .query_row("SELECT id FROM t", [], |row| row.get(0))
+///     .unwrap();
+/// assert_eq!(stored, ulys);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct UlysBlob(pub Ulys);
+
+impl ToSql for UlysBlob {
+	fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+		self.0.to_sql()
+	}
+}
+
+impl FromSql for UlysBlob {
+	fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+		let blob = value.as_blob()?;
+		let bytes: &[u8; 16] = blob.try_into().map_err(|_| FromSqlError::InvalidBlobSize {
+			expected_size: 16,
+			blob_size: blob.len(),
+		})?;
+		Ok(UlysBlob(Ulys::from(bytes)))
+	}
+}
+
+impl From<Ulys> for UlysBlob {
+	fn from(ulys: Ulys) -> Self {
+		UlysBlob(ulys)
+	}
+}
+
+impl From<UlysBlob> for Ulys {
+	fn from(blob: UlysBlob) -> Self {
+		blob.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rusqlite::{params, Connection};
+
+	fn connection() -> Connection {
+		let conn = Connection::open_in_memory().unwrap();
+		conn.execute("CREATE TABLE ids (text_id TEXT, blob_id BLOB)", [])
+			.unwrap();
+		conn
+	}
+
+	#[test]
+	fn rusqlite_default_cycle() {
+		let conn = connection();
+		let ids = [Ulys::new(), Ulys::new(), Ulys::new()];
+
+		let mut insert = conn
+			.prepare("INSERT INTO ids (blob_id) VALUES (?1)")
+			.unwrap();
+		for ulys in ids {
+			insert.execute(params![ulys]).unwrap();
+		}
+
+		let length: i64 = conn
+			.query_row("SELECT length(blob_id) FROM ids LIMIT 1", [], |row| {
+				row.get(0)
+			})
+			.unwrap();
+		assert_eq!(length, 16);
+
+		let mut select = conn
+			.prepare("SELECT blob_id FROM ids WHERE blob_id = ?1")
+			.unwrap();
+		for ulys in ids {
+			let stored: Ulys = select.query_row(params![ulys], |row| row.get(0)).unwrap();
+			assert_eq!(stored, ulys);
+		}
+
+		// Blobs compare bytewise, so ranges follow the order of the IDs.
+		let mut sorted = ids;
+		sorted.sort_unstable();
+		let mut select = conn
+			.prepare("SELECT blob_id FROM ids WHERE blob_id > ?1 ORDER BY blob_id")
+			.unwrap();
+		let after: Vec<Ulys> = select
+			.query_map(params![sorted[0]], |row| row.get(0))
+			.unwrap()
+			.collect::<Result<_, _>>()
+			.unwrap();
+		assert_eq!(after, sorted[1..]);
+	}
+
+	#[test]
+	fn rusqlite_text_cycle() {
+		let conn = connection();
+		let ulys = Ulys::new();
+
+		conn.execute(
+			"INSERT INTO ids (text_id) VALUES (?1)",
+			params![UlysText(ulys)],
+		)
+		.unwrap();
+
+		let text: String = conn
+			.query_row("SELECT text_id FROM ids", [], |row| row.get(0))
+			.unwrap();
+		assert_eq!(text, ulys.to_string());
+
+		let stored: Ulys = conn
+			.query_row("SELECT text_id FROM ids", [], |row| row.get(0))
+			.unwrap();
+		assert_eq!(stored, ulys);
+	}
+
+	#[test]
+	fn rusqlite_blob_cycle() {
+		let conn = connection();
+		let ulys = Ulys::new();
+
+		conn.execute(
+			"INSERT INTO ids (blob_id) VALUES (?1)",
+			params![UlysBlob(ulys)],
+		)
+		.unwrap();
+
+		let bytes: Vec<u8> = conn
+			.query_row("SELECT blob_id FROM ids", [], |row| row.get(0))
+			.unwrap();
+		assert_eq!(bytes, ulys.0.to_be_bytes());
+
+		let stored: UlysBlob = conn
+			.query_row("SELECT blob_id FROM ids", [], |row| row.get(0))
+			.unwrap();
+		assert_eq!(Ulys::from(stored), ulys);
+	}
+
+	#[test]
+	fn rusqlite_invalid_values() {
+		let conn = connection();
+		conn.execute(
+			"INSERT INTO ids (text_id, blob_id) VALUES ('abc', x'0102')",
+			[],
+		)
+		.unwrap();
+
+		let text = conn.query_row("SELECT text_id FROM ids", [], |row| row.get::<_, Ulys>(0));
+		assert!(text.is_err());
+
+		let blob = conn.query_row("SELECT blob_id FROM ids", [], |row| row.get::<_, Ulys>(0));
+		assert!(blob.is_err());
+
+		let integer = conn.query_row("SELECT 42", [], |row| row.get::<_, Ulys>(0));
+		assert!(integer.is_err());
+
+		let blob = conn.query_row("SELECT blob_id FROM ids", [], |row| {
+			row.get::<_, UlysBlob>(0)
+		});
+		assert!(blob.is_err());
+	}
+}
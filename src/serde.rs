@@ -1,43 +1,303 @@
 //! Serialization and deserialization.
 //!
 //! By default, serialization and deserialization go through ULYSes 26-character
-//! canonical string representation as set by the ULID standard.
+//! canonical string representation as set by the ULID standard in
+//! human-readable formats such as JSON, YAML or TOML, and through their 16
+//! big-endian bytes in binary formats such as `bincode` or `rmp-serde`, like
+//! `uuid` does. Deserialization accepts both forms in either kind of format.
 //!
 //! ULYSes can optionally be serialized as u128 integers using the `ulys_as_u128`
-//! module. See the module's documentation for examples.
+//! module, or as 16 bytes using the `ulys_as_bytes` module. See the modules'
+//! documentation for examples.
+//!
+//! The representation can also be chosen at the type level with the
+//! [`UlysString`], [`UlysU128`] and [`UlysBytes`] wrappers, which avoids
+//! annotating every field of a DTO.
 
-use crate::Ulys;
-use base32::Alphabet;
+use crate::{NonNilUlys, TypedUlys, Ulys};
+use core::fmt;
+use core::ops::Deref;
+use serde::de::{SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-/// Serializes a ULYS as a base32 string.
+/// Serializes a ULYS as a base32 string in human-readable formats, and as 16
+/// big-endian bytes otherwise.
 ///
 /// # Errors
 ///
-/// This function will return an error if the ULYS cannot be serialized as a base32 string.
+/// This function will return an error if the ULYS cannot be serialized.
 impl Serialize for Ulys {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: Serializer,
 	{
-		base32::encode(Alphabet::Crockford, &self.0.to_be_bytes())
-			.to_lowercase()
-			.serialize(serializer)
+		if serializer.is_human_readable() {
+			serializer.collect_str(self)
+		} else {
+			ulys_as_bytes::serialize(self, serializer)
+		}
 	}
 }
 
-/// Deserializes a ULYS from a base32 string.
+/// Deserializes a ULYS from a base32 string or 16 big-endian bytes.
 ///
 /// # Errors
 ///
-/// This function will return an error if the ULYS is not a valid base32 string.
+/// This function will return an error if the ULYS is neither a valid base32
+/// string nor exactly 16 bytes.
 impl<'de> Deserialize<'de> for Ulys {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
 		D: Deserializer<'de>,
 	{
-		let deserialized_str = String::deserialize(deserializer)?;
-		Self::from_string(&deserialized_str).map_err(serde::de::Error::custom)
+		if deserializer.is_human_readable() {
+			deserializer.deserialize_str(UlysVisitor)
+		} else {
+			deserializer.deserialize_bytes(UlysVisitor)
+		}
+	}
+}
+
+/// Serializes a tagged ULYS exactly like the plain ULYS it wraps.
+impl<T> Serialize for TypedUlys<T> {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		self.into_ulys().serialize(serializer)
+	}
+}
+
+/// Deserializes a tagged ULYS exactly like the plain ULYS it wraps.
+impl<'de, T> Deserialize<'de> for TypedUlys<T> {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		Ulys::deserialize(deserializer).map(TypedUlys::from_ulys)
+	}
+}
+
+/// Serializes a non-nil ULYS exactly like the plain ULYS it wraps.
+impl Serialize for NonNilUlys {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		self.get().serialize(serializer)
+	}
+}
+
+/// Deserializes a non-nil ULYS like a plain ULYS.
+///
+/// # Errors
+///
+/// This function will return an error if the ULYS is not valid or is nil.
+impl<'de> Deserialize<'de> for NonNilUlys {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let ulys = Ulys::deserialize(deserializer)?;
+		NonNilUlys::new(ulys).ok_or_else(|| serde::de::Error::custom("nil ulys"))
+	}
+}
+
+/// Implements the conversions shared by the representation wrappers.
+macro_rules! representation_wrapper {
+	($name:ident) => {
+		impl Deref for $name {
+			type Target = Ulys;
+
+			fn deref(&self) -> &Ulys {
+				&self.0
+			}
+		}
+
+		impl From<Ulys> for $name {
+			fn from(ulys: Ulys) -> Self {
+				$name(ulys)
+			}
+		}
+
+		impl From<$name> for Ulys {
+			fn from(wrapper: $name) -> Self {
+				wrapper.0
+			}
+		}
+
+		impl fmt::Display for $name {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				fmt::Display::fmt(&self.0, f)
+			}
+		}
+	};
+}
+
+/// A ULYS serialized as its 26-character canonical string.
+///
+/// This is the representation of a plain [`Ulys`] in human-readable formats,
+/// kept in binary formats too, where a plain [`Ulys`] uses 16 bytes.
+///
+/// # Examples
+/// ```
+/// # use ulys::Ulys;
+/// # use ulys::serde::UlysString;
+/// # use serde_derive::{Serialize, Deserialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct StringExample {
+///     identifier: UlysString
+/// }
+/// ```
+#[derive(Debug, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct UlysString(pub Ulys);
+
+representation_wrapper!(UlysString);
+
+impl Serialize for UlysString {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.collect_str(&self.0)
+	}
+}
+
+impl<'de> Deserialize<'de> for UlysString {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_str(UlysVisitor).map(UlysString)
+	}
+}
+
+/// A ULYS serialized as its inner u128 integer.
+///
+/// This is the type-level equivalent of the [`ulys_as_u128`] module.
+#[derive(Debug, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct UlysU128(pub Ulys);
+
+representation_wrapper!(UlysU128);
+
+impl Serialize for UlysU128 {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		ulys_as_u128::serialize(&self.0, serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for UlysU128 {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		ulys_as_u128::deserialize(deserializer).map(UlysU128)
+	}
+}
+
+/// A ULYS serialized as its 16 big-endian bytes.
+///
+/// This is the type-level equivalent of the [`ulys_as_bytes`] module.
+/// Formats with native byte strings store the 16 bytes directly; others,
+/// like JSON, fall back to a sequence of 16 integers. Both forms are
+/// accepted when deserializing.
+#[derive(Debug, Default, PartialOrd, Ord, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct UlysBytes(pub Ulys);
+
+representation_wrapper!(UlysBytes);
+
+impl Serialize for UlysBytes {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		ulys_as_bytes::serialize(&self.0, serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for UlysBytes {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		ulys_as_bytes::deserialize(deserializer).map(UlysBytes)
+	}
+}
+
+/// Visits the 16 big-endian bytes of a ULYS.
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+	type Value = Ulys;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("16 bytes")
+	}
+
+	fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+	where
+		E: serde::de::Error,
+	{
+		Ulys::try_from(v).map_err(|_| E::invalid_length(v.len(), &self))
+	}
+
+	fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+	where
+		A: SeqAccess<'de>,
+	{
+		let mut bytes = [0; 16];
+		for (i, byte) in bytes.iter_mut().enumerate() {
+			*byte = seq
+				.next_element()?
+				.ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+		}
+		if seq.next_element::<u8>()?.is_some() {
+			return Err(serde::de::Error::invalid_length(17, &self));
+		}
+		Ok(Ulys(u128::from_be_bytes(bytes)))
+	}
+}
+
+/// Visits a ULYS as its canonical string or its 16 big-endian bytes.
+///
+/// Bytes of the length of the string form are decoded as ASCII, since
+/// binary formats usually encode strings like byte arrays: data serialized
+/// before ULYSes were stored as bytes can still be read.
+struct UlysVisitor;
+
+impl<'de> Visitor<'de> for UlysVisitor {
+	type Value = Ulys;
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		formatter.write_str("a ULYS string or 16 bytes")
+	}
+
+	fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+	where
+		E: serde::de::Error,
+	{
+		Ulys::from_string(v).map_err(E::custom)
+	}
+
+	fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+	where
+		E: serde::de::Error,
+	{
+		if v.len() == Ulys::ULYS_LEN {
+			Ulys::from_ascii_bytes(v).map_err(E::custom)
+		} else {
+			Ulys::try_from(v).map_err(|_| E::invalid_length(v.len(), &self))
+		}
+	}
+
+	fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+	where
+		A: SeqAccess<'de>,
+	{
+		BytesVisitor.visit_seq(seq)
 	}
 }
 
@@ -87,6 +347,105 @@ pub mod ulys_as_u128 {
 		let deserialized_u128 = u128::deserialize(deserializer)?;
 		Ok(Ulys(deserialized_u128))
 	}
+
+	/// Serialization and deserialization of optional ULYSes through their
+	/// inner u128 type.
+	///
+	/// `None` is serialized as the format's null value, such as JSON `null`.
+	/// Add `#[serde(default)]` to also accept a missing field.
+	///
+	/// # Examples
+	/// ```
+	/// # use ulys::Ulys;
+	/// # use ulys::serde::ulys_as_u128;
+	/// # use serde_derive::{Serialize, Deserialize};
+	/// #[derive(Serialize, Deserialize)]
+	/// struct OptionalU128Example {
+	///     #[serde(with = "ulys_as_u128::option")]
+	///     identifier: Option<Ulys>
+	/// }
+	/// ```
+	pub mod option {
+		use crate::Ulys;
+		use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+		/// Serializes an optional ULYS as an optional u128 type.
+		///
+		/// # Errors
+		///
+		/// This function will return an error if the ULYS cannot be serialized as a u128 value.
+		#[allow(clippy::ref_option)] // `serde(with)` passes a reference to the field
+		pub fn serialize<S>(value: &Option<Ulys>, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			S: Serializer,
+		{
+			value.map(|ulys| ulys.0).serialize(serializer)
+		}
+
+		/// Deserializes an optional ULYS from an optional u128 type.
+		///
+		/// # Errors
+		///
+		/// This function will return an error if the value is neither null nor a valid u128 value.
+		pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Ulys>, D::Error>
+		where
+			D: Deserializer<'de>,
+		{
+			Ok(Option::<u128>::deserialize(deserializer)?.map(Ulys))
+		}
+	}
+}
+
+/// Serialization and deserialization of ULYSes through their 16 big-endian
+/// bytes.
+///
+/// To use it, annotate a field with
+/// `#[serde(with = "ulys_as_bytes")]`,
+/// `#[serde(serialize_with = "ulys_as_bytes")]`, or
+/// `#[serde(deserialize_with = "ulys_as_bytes")]`.
+///
+/// # Examples
+/// ```
+/// # use ulys::Ulys;
+/// # use ulys::serde::ulys_as_bytes;
+/// # use serde_derive::{Serialize, Deserialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct BytesExample {
+///     #[serde(with = "ulys_as_bytes")]
+///     identifier: Ulys
+/// }
+/// ```
+pub mod ulys_as_bytes {
+	use super::BytesVisitor;
+	use crate::Ulys;
+	use serde::{Deserializer, Serializer};
+
+	/// Serializes a ULYS as 16 big-endian bytes.
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the ULYS cannot be serialized as bytes.
+	pub fn serialize<S>(value: &Ulys, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.serialize_bytes(&value.0.to_be_bytes())
+	}
+
+	/// Deserializes a ULYS from 16 big-endian bytes.
+	///
+	/// Both byte arrays, borrowed or not, and sequences of 16 integers are
+	/// accepted.
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the input is not exactly 16 bytes.
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Ulys, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_bytes(BytesVisitor)
+	}
 }
 
 /// Serialization and deserialization of ULYSes through UUID strings.
@@ -138,4 +497,236 @@ pub mod ulys_as_uuid {
 		let de_uuid = Uuid::parse_str(&de_string).map_err(serde::de::Error::custom)?;
 		Ok(Ulys::from(de_uuid))
 	}
+
+	/// Serialization and deserialization of optional ULYSes through UUID
+	/// strings.
+	///
+	/// `None` is serialized as the format's null value, such as JSON `null`.
+	/// Add `#[serde(default)]` to also accept a missing field.
+	///
+	/// # Examples
+	/// ```
+	/// # use ulys::Ulys;
+	/// # use ulys::serde::ulys_as_uuid;
+	/// # use serde_derive::{Serialize, Deserialize};
+	/// #[derive(Serialize, Deserialize)]
+	/// struct OptionalUuidExample {
+	///     #[serde(with = "ulys_as_uuid::option")]
+	///     identifier: Option<Ulys>
+	/// }
+	/// ```
+	pub mod option {
+		use crate::Ulys;
+		use serde::{Deserialize, Deserializer, Serialize, Serializer};
+		use uuid::Uuid;
+
+		/// Converts the optional ULYS to a UUID and serializes it as an
+		/// optional string.
+		///
+		/// # Errors
+		///
+		/// This function will return an error if the ULYS cannot be serialized as a UUID string.
+		#[allow(clippy::ref_option)] // `serde(with)` passes a reference to the field
+		pub fn serialize<S>(value: &Option<Ulys>, serializer: S) -> Result<S::Ok, S::Error>
+		where
+			S: Serializer,
+		{
+			value
+				.map(|ulys| Uuid::from(ulys).to_string())
+				.serialize(serializer)
+		}
+
+		/// Deserializes an optional ULYS from an optional string containing a
+		/// UUID.
+		///
+		/// # Errors
+		///
+		/// This function will return an error if the value is neither null nor a valid UUID string.
+		pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Ulys>, D::Error>
+		where
+			D: Deserializer<'de>,
+		{
+			Option::<String>::deserialize(deserializer)?
+				.map(|de_string| {
+					let de_uuid = Uuid::parse_str(&de_string).map_err(serde::de::Error::custom)?;
+					Ok(Ulys::from(de_uuid))
+				})
+				.transpose()
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ulys_as_bytes, UlysBytes, UlysString, UlysU128};
+	use crate::{NonNilUlys, TypedUlys, Ulys};
+	use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token};
+
+	struct User;
+
+	// cSpell:disable
+	const ULYS: Ulys = Ulys(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+	const ULYS_STR: &str = "041061050r3gg28a1c60t3gf20";
+	const ULYS_BYTES: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+	// cSpell:enable
+
+	#[test]
+	fn serde_human_readable_uses_string() {
+		assert_eq!(ULYS.to_string(), ULYS_STR);
+		assert_tokens(&ULYS.readable(), &[Token::Str(ULYS_STR)]);
+		assert_de_tokens(&ULYS.readable(), &[Token::Bytes(ULYS_BYTES)]);
+		assert_de_tokens_error::<serde_test::Readable<Ulys>>(
+			&[Token::Str("041061050r3gg28a1c60t3gf2")],
+			"invalid length",
+		);
+	}
+
+	#[test]
+	fn serde_deserializes_borrowed_and_owned_strings() {
+		assert_de_tokens(&ULYS.readable(), &[Token::BorrowedStr(ULYS_STR)]);
+		assert_de_tokens(&ULYS.readable(), &[Token::String(ULYS_STR)]);
+
+		// Borrowed straight from the input, or unescaped into a scratch buffer.
+		let json = format!("\"{ULYS_STR}\"");
+		assert_eq!(serde_json::from_str::<Ulys>(&json).unwrap(), ULYS);
+		let escaped = format!("\"\\u0030{}\"", &ULYS_STR[1..]);
+		assert_eq!(serde_json::from_str::<Ulys>(&escaped).unwrap(), ULYS);
+	}
+
+	#[test]
+	fn serde_compact_uses_bytes() {
+		assert_tokens(&ULYS.compact(), &[Token::Bytes(ULYS_BYTES)]);
+		assert_de_tokens(&ULYS.compact(), &[Token::Str(ULYS_STR)]);
+		// Strings previously written by binary formats come back as bytes.
+		assert_de_tokens(&ULYS.compact(), &[Token::Bytes(ULYS_STR.as_bytes())]);
+		assert_de_tokens_error::<serde_test::Compact<Ulys>>(
+			&[Token::Bytes(&ULYS_BYTES[..15])],
+			"invalid length 15, expected a ULYS string or 16 bytes",
+		);
+
+		assert_tokens(&UlysString(ULYS).compact(), &[Token::Str(ULYS_STR)]);
+		assert_tokens(&UlysBytes(ULYS).readable(), &[Token::Bytes(ULYS_BYTES)]);
+	}
+
+	#[test]
+	fn option_helpers_json() {
+		use super::{ulys_as_u128, ulys_as_uuid};
+		use serde_derive::{Deserialize, Serialize};
+
+		#[derive(Debug, PartialEq, Serialize, Deserialize)]
+		struct Row {
+			#[serde(with = "ulys_as_u128::option")]
+			integer: Option<Ulys>,
+			#[serde(default, with = "ulys_as_uuid::option")]
+			uuid: Option<Ulys>,
+		}
+
+		let row = Row {
+			integer: Some(ULYS),
+			uuid: Some(ULYS),
+		};
+		let json = serde_json::to_string(&row).unwrap();
+		assert_eq!(
+			json,
+			format!(
+				r#"{{"integer":{},"uuid":"01020304-0506-0708-090a-0b0c0d0e0f10"}}"#,
+				ULYS.0
+			)
+		);
+		assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), row);
+
+		let empty = Row {
+			integer: None,
+			uuid: None,
+		};
+		let json = serde_json::to_string(&empty).unwrap();
+		assert_eq!(json, r#"{"integer":null,"uuid":null}"#);
+		assert_eq!(serde_json::from_str::<Row>(&json).unwrap(), empty);
+		assert_eq!(
+			serde_json::from_str::<Row>(r#"{"integer":null}"#).unwrap(),
+			empty
+		);
+		assert!(serde_json::from_str::<Row>(r#"{"integer":null,"uuid":"nope"}"#).is_err());
+	}
+
+	#[test]
+	fn typed_serde_matches_plain() {
+		let user = TypedUlys::<User>::new();
+		let typed_json = serde_json::to_string(&user).unwrap();
+		let plain_json = serde_json::to_string(&user.into_ulys()).unwrap();
+
+		assert_eq!(typed_json, plain_json);
+
+		let parsed: TypedUlys<User> = serde_json::from_str(&typed_json).unwrap();
+		assert_eq!(parsed, user);
+		assert_eq!(serde_json::from_str::<Ulys>(&typed_json).unwrap(), *user);
+	}
+
+	#[test]
+	fn non_nil_serde_matches_plain() {
+		let ulys = Ulys::new();
+		let non_nil = NonNilUlys::new(ulys).unwrap();
+		let json = serde_json::to_string(&non_nil).unwrap();
+
+		assert_eq!(json, serde_json::to_string(&ulys).unwrap());
+		assert_eq!(serde_json::from_str::<NonNilUlys>(&json).unwrap(), non_nil);
+
+		let nil_json = serde_json::to_string(&Ulys::default()).unwrap();
+		assert!(serde_json::from_str::<NonNilUlys>(&nil_json).is_err());
+	}
+
+	#[test]
+	fn representation_wrappers_json() {
+		let ulys = Ulys(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+
+		let string = serde_json::to_string(&UlysString(ulys)).unwrap();
+		assert_eq!(string, format!("\"{ulys}\""));
+		assert_eq!(
+			serde_json::from_str::<UlysString>(&string).unwrap(),
+			UlysString(ulys)
+		);
+
+		let integer = serde_json::to_string(&UlysU128(ulys)).unwrap();
+		assert_eq!(integer, ulys.0.to_string());
+		assert_eq!(
+			serde_json::from_str::<UlysU128>(&integer).unwrap(),
+			UlysU128(ulys)
+		);
+
+		let bytes = serde_json::to_string(&UlysBytes(ulys)).unwrap();
+		assert_eq!(bytes, "[1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16]");
+		assert_eq!(
+			serde_json::from_str::<UlysBytes>(&bytes).unwrap(),
+			UlysBytes(ulys)
+		);
+		assert!(serde_json::from_str::<UlysBytes>("[1,2,3]").is_err());
+	}
+
+	#[test]
+	fn representation_wrappers_conversions() {
+		let ulys = Ulys::new();
+		let wrapped = UlysBytes::from(ulys);
+
+		assert_eq!(wrapped.datetime(), ulys.datetime());
+		assert_eq!(Ulys::from(wrapped), ulys);
+		assert_eq!(Ulys::from(UlysString::from(ulys)), ulys);
+		assert_eq!(Ulys::from(UlysU128::from(ulys)), ulys);
+	}
+
+	#[test]
+	fn ulys_as_bytes_from_byte_array() {
+		use serde::de::value::{BorrowedBytesDeserializer, BytesDeserializer, Error};
+
+		let ulys = Ulys::new();
+		let bytes = ulys.0.to_be_bytes();
+
+		let deserializer = BytesDeserializer::<Error>::new(&bytes);
+		assert_eq!(ulys_as_bytes::deserialize(deserializer), Ok(ulys));
+
+		let deserializer = BorrowedBytesDeserializer::<Error>::new(&bytes);
+		assert_eq!(ulys_as_bytes::deserialize(deserializer), Ok(ulys));
+
+		let deserializer = BytesDeserializer::<Error>::new(&bytes[..10]);
+		assert!(ulys_as_bytes::deserialize(deserializer).is_err());
+	}
 }
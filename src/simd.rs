@@ -0,0 +1,311 @@
+//! Vectorized validation and decoding of Crockford Base32 strings.
+//!
+//! The vectors are `wide` types, which compile to AVX2 or NEON instructions
+//! when the target enables them and to SSE2 or portable code otherwise.
+
+use crate::base32::{self, DecodeError};
+use crate::Ulys;
+use wide::{u8x32, CmpEq};
+
+impl Ulys {
+	/// Checks that a string has the length and alphabet of an encoded Ulys
+	///
+	/// On CPUs with AVX2 (detected at runtime with `std`) or NEON, all 26
+	/// characters are checked at once with vector instructions, which is
+	/// faster than the scalar [`Ulys::decode`] when pre-validating large
+	/// volumes of IDs. Elsewhere the scalar decoder is used, as emulated
+	/// vectors would be slower. The verdict is exactly the one of
+	/// [`Ulys::from_string`]: this returns `true` if and only if `from_string`
+	/// succeeds, so ambiguous letters (`I`, `L`, `O`) and `U` are rejected,
+	/// in either case.
+	#[must_use]
+	pub fn is_valid_crockford(s: &str) -> bool {
+		decode(s.as_bytes()).is_ok()
+	}
+}
+
+/// Decodes Crockford Base32 ASCII bytes, in either case
+///
+/// The vector path is only taken with 256-bit (AVX2) or NEON vectors:
+/// emulated with two SSE2 halves, it is slower than the scalar
+/// [`base32::decode`]. On `x86_64` builds without AVX2, the CPU is checked
+/// at runtime and the AVX2 intrinsics are used when it supports them.
+pub(crate) fn decode(bytes: &[u8]) -> Result<u128, DecodeError> {
+	#[cfg(all(feature = "std", target_arch = "x86_64", not(target_feature = "avx2")))]
+	if std::is_x86_feature_detected!("avx2") {
+		// SAFETY: the CPU supports AVX2.
+		return unsafe { avx2::decode(bytes) };
+	}
+
+	if cfg!(any(target_feature = "avx2", target_feature = "neon")) {
+		decode_vectorized(bytes)
+	} else {
+		base32::decode(bytes)
+	}
+}
+
+/// Decodes Crockford Base32 ASCII bytes, in either case, with vector
+/// instructions
+///
+/// The result is exactly the one of the scalar [`base32::decode`], which
+/// reports the error when the bytes are not a valid encoded Ulys.
+fn decode_vectorized(bytes: &[u8]) -> Result<u128, DecodeError> {
+	let Some(digits) = <&[u8; Ulys::ULYS_LEN]>::try_from(bytes)
+		.ok()
+		.and_then(decode_lanes)
+	else {
+		return base32::decode(bytes);
+	};
+
+	// The digits are packed 12 at a time into 60-bit words, cheaper to shift
+	// than a `u128`.
+	let digits = digits.to_array();
+	let pack = |digits: &[u8]| {
+		digits
+			.iter()
+			.fold(0_u64, |word, &digit| word << 5 | u64::from(digit))
+	};
+	let high = u128::from(pack(&digits[..12]));
+	let low = u128::from(pack(&digits[12..24]));
+
+	Ok(high << 68 | low << 8 | u128::from(digits[24]) << 3 | u128::from(digits[25] >> 2))
+}
+
+/// Returns the value of each of the 26 characters in the first lanes, or
+/// `None` when any of them is not part of the alphabet
+fn decode_lanes(bytes: &[u8; Ulys::ULYS_LEN]) -> Option<u8x32> {
+	// The 6 unused lanes are padded with a valid digit.
+	let mut lanes = [b'0'; 32];
+	lanes[..Ulys::ULYS_LEN].copy_from_slice(bytes);
+	let lanes = u8x32::new(lanes);
+
+	let digit = in_range(lanes, b'0', b'9');
+	// Setting bit 5 lowercases ASCII letters and maps no other byte into
+	// the `a..=z` range.
+	let lower = lanes | u8x32::splat(0x20);
+	let excluded = EXCLUDED
+		.map(|letter| lower.simd_eq(u8x32::splat(letter)))
+		.into_iter()
+		.fold(u8x32::splat(0), |acc, mask| acc | mask);
+	let letter = in_range(lower, b'a', b'z') & !excluded;
+
+	if !(digit | letter).all() {
+		return None;
+	}
+
+	// Each excluded letter before a letter shifts its value down by one.
+	let skipped = EXCLUDED
+		.map(|letter| {
+			lower
+				.saturating_sub(u8x32::splat(letter))
+				.min(u8x32::splat(1))
+		})
+		.into_iter()
+		.fold(u8x32::splat(0), |acc, count| acc + count);
+	let letter_value = lower - (b'a' - 10) - skipped;
+
+	Some(digit.blend(lanes - b'0', letter_value))
+}
+
+/// The letters missing from the alphabet, in order
+const EXCLUDED: [u8; 4] = [b'i', b'l', b'o', b'u'];
+
+/// Decoding with AVX2 intrinsics, for CPUs detected at runtime
+#[cfg(all(feature = "std", target_arch = "x86_64", not(target_feature = "avx2")))]
+mod avx2 {
+	use super::EXCLUDED;
+	use crate::base32::{self, DecodeError};
+	use crate::Ulys;
+	use core::arch::x86_64::{
+		__m256i, _mm256_add_epi8, _mm256_andnot_si256, _mm256_blendv_epi8, _mm256_cmpeq_epi8,
+		_mm256_loadu_si256, _mm256_madd_epi16, _mm256_maddubs_epi16, _mm256_min_epu8,
+		_mm256_movemask_epi8, _mm256_or_si256, _mm256_set1_epi16, _mm256_set1_epi32,
+		_mm256_set1_epi8, _mm256_setzero_si256, _mm256_storeu_si256, _mm256_sub_epi8,
+		_mm256_subs_epu8,
+	};
+
+	/// Decodes Crockford Base32 ASCII bytes, in either case, with AVX2
+	/// instructions
+	///
+	/// This follows [`super::decode_vectorized`], with the digits packed by
+	/// multiply-adds instead of shifts.
+	#[target_feature(enable = "avx2")]
+	pub(super) fn decode(bytes: &[u8]) -> Result<u128, DecodeError> {
+		let Ok(bytes) = <&[u8; Ulys::ULYS_LEN]>::try_from(bytes) else {
+			return base32::decode(bytes);
+		};
+
+		// The 6 unused lanes are padded with a valid digit.
+		let mut lanes = [b'0'; 32];
+		lanes[..Ulys::ULYS_LEN].copy_from_slice(bytes);
+		// SAFETY: `lanes` holds the 32 bytes read, without alignment.
+		let lanes = unsafe { _mm256_loadu_si256(lanes.as_ptr().cast()) };
+
+		let digit_value = _mm256_sub_epi8(lanes, splat(b'0'));
+		let digit = at_most(digit_value, 9);
+		let lower = _mm256_or_si256(lanes, splat(0x20));
+		let letter_offset = _mm256_sub_epi8(lower, splat(b'a'));
+		let mut letter = at_most(letter_offset, b'z' - b'a');
+		let mut skipped = _mm256_setzero_si256();
+		for excluded in EXCLUDED {
+			letter = _mm256_andnot_si256(_mm256_cmpeq_epi8(lower, splat(excluded)), letter);
+			skipped = _mm256_add_epi8(
+				skipped,
+				_mm256_min_epu8(_mm256_subs_epu8(lower, splat(excluded)), splat(1)),
+			);
+		}
+
+		if _mm256_movemask_epi8(_mm256_or_si256(digit, letter)) != -1 {
+			return base32::decode(bytes);
+		}
+
+		let letter_value = _mm256_sub_epi8(_mm256_add_epi8(letter_offset, splat(10)), skipped);
+		let digits = _mm256_blendv_epi8(letter_value, digit_value, digit);
+
+		// Pairs of digits make 10 bits, then pairs of pairs make 20 bits.
+		let pairs = _mm256_maddubs_epi16(digits, _mm256_set1_epi16(0x0120));
+		let quads = _mm256_madd_epi16(pairs, _mm256_set1_epi32(0x0001_0400));
+		let mut words = [0_u32; 8];
+		// SAFETY: `words` holds the 32 bytes written, without alignment.
+		unsafe { _mm256_storeu_si256(words.as_mut_ptr().cast(), quads) };
+
+		// The 7th word holds the last 2 digits in its top 10 of 20 bits.
+		let value = words[..6]
+			.iter()
+			.fold(0, |value, &word| value << 20 | u128::from(word));
+		Ok(value << 8 | u128::from(words[6] >> 12))
+	}
+
+	/// Returns a mask of the lanes holding a byte at most `max`
+	#[target_feature(enable = "avx2")]
+	fn at_most(lanes: __m256i, max: u8) -> __m256i {
+		_mm256_cmpeq_epi8(_mm256_min_epu8(lanes, splat(max)), lanes)
+	}
+
+	/// Returns `byte` in every lane
+	#[target_feature(enable = "avx2")]
+	fn splat(byte: u8) -> __m256i {
+		_mm256_set1_epi8(byte.cast_signed())
+	}
+}
+
+/// Returns a mask of the lanes holding a byte within `low..=high`
+fn in_range(lanes: u8x32, low: u8, high: u8) -> u8x32 {
+	let offset = lanes - u8x32::splat(low);
+	offset.min(u8x32::splat(high - low)).simd_eq(offset)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use rand::seq::IndexedRandom;
+	use rand::RngExt;
+
+	#[test]
+	fn test_is_valid_crockford_matches_from_string() {
+		let mut source = rand::rng();
+		let alphabet: Vec<u8> = (0..=127).collect();
+		let valid = b"0123456789abcdefghjkmnpqrstvwxyzABCDEFGHJKMNPQRSTVWXYZ";
+
+		for _ in 0..100_000 {
+			let len = if source.random_ratio(1, 10) {
+				source.random_range(0..40)
+			} else {
+				Ulys::ULYS_LEN
+			};
+			// Mostly valid characters, so both verdicts are well represented.
+			let bytes: Vec<u8> = (0..len)
+				.map(|_| {
+					if source.random_ratio(1, 30) {
+						*alphabet.choose(&mut source).unwrap()
+					} else {
+						*valid.choose(&mut source).unwrap()
+					}
+				})
+				.collect();
+			let s = String::from_utf8(bytes).unwrap();
+
+			assert_eq!(
+				Ulys::is_valid_crockford(&s),
+				Ulys::from_string(&s).is_ok(),
+				"{s:?}"
+			);
+		}
+	}
+
+	type Decoder = fn(&[u8]) -> Result<u128, DecodeError>;
+
+	/// Returns the vectorized decoders available on this CPU
+	fn decoders() -> Vec<Decoder> {
+		#[allow(unused_mut)]
+		let mut decoders: Vec<Decoder> = vec![decode, decode_vectorized];
+
+		#[cfg(all(feature = "std", target_arch = "x86_64", not(target_feature = "avx2")))]
+		if std::is_x86_feature_detected!("avx2") {
+			// SAFETY: the CPU supports AVX2.
+			decoders.push(|bytes| unsafe { avx2::decode(bytes) });
+		}
+
+		decoders
+	}
+
+	#[test]
+	fn test_decode_matches_scalar() {
+		let mut source = rand::rng();
+		let alphabet: Vec<u8> = (0..=255).collect();
+		let valid = b"0123456789abcdefghjkmnpqrstvwxyzABCDEFGHJKMNPQRSTVWXYZ";
+		let decoders = decoders();
+
+		for _ in 0..100_000 {
+			let len = if source.random_ratio(1, 10) {
+				source.random_range(0..40)
+			} else {
+				Ulys::ULYS_LEN
+			};
+			let bytes: Vec<u8> = (0..len)
+				.map(|_| {
+					if source.random_ratio(1, 30) {
+						*alphabet.choose(&mut source).unwrap()
+					} else {
+						*valid.choose(&mut source).unwrap()
+					}
+				})
+				.collect();
+
+			for decode in &decoders {
+				assert_eq!(decode(&bytes), base32::decode(&bytes), "{bytes:?}");
+			}
+		}
+	}
+
+	#[test]
+	fn test_decode_every_symbol() {
+		for decode in decoders() {
+			for symbol in 0..=u8::MAX {
+				let bytes = [symbol; Ulys::ULYS_LEN];
+				assert_eq!(decode(&bytes), base32::decode(&bytes), "{symbol}");
+			}
+			for value in [0, 1, u128::MAX, 0x0123_4567_89ab_cdef_0123_4567_89ab_cdef] {
+				let encoded = base32::encode(value);
+				assert_eq!(decode(&encoded), Ok(value));
+				assert_eq!(decode(&encoded.to_ascii_uppercase()), Ok(value));
+			}
+		}
+	}
+
+	#[test]
+	fn test_is_valid_crockford_edge_cases() {
+		assert!(Ulys::is_valid_crockford(&Ulys::new().to_string()));
+		assert!(Ulys::is_valid_crockford("0123456789ABCDEFGHJKMNPQRS"));
+		assert!(Ulys::is_valid_crockford("tvwxyzTVWXYZ00000000000000"));
+		assert!(!Ulys::is_valid_crockford(""));
+		assert!(!Ulys::is_valid_crockford("0123456789abcdefghjkmnpqr"));
+		assert!(!Ulys::is_valid_crockford("0123456789abcdefghjkmnpqrs0"));
+		for invalid in [
+			"i", "I", "l", "L", "o", "O", "u", "U", "@", "[", "`", "{", "/", ":", "é",
+		] {
+			let s = format!("{invalid}{}", &"0".repeat(Ulys::ULYS_LEN)[invalid.len()..]);
+			assert!(!Ulys::is_valid_crockford(&s), "{s:?}");
+		}
+	}
+}
@@ -0,0 +1,200 @@
+//! Conversions between ULYS and `sqlx` values for Postgres and `SQLite`.
+//!
+//! On Postgres, ULYSes are bound as `uuid` values through the UUID with the
+//! same bits, and can be read from `uuid` or 16-byte `bytea` columns. On
+//! `SQLite`, they are bound as 16-byte big-endian `BLOB`s, and can be read
+//! from `BLOB` columns or from `TEXT` columns holding the canonical string.
+//!
+//! The backends have separate features, `sqlx-postgres` and `sqlx-sqlite`.
+//! The latter links the `SQLite` library bundled with `sqlx`.
+//!
+//! # Examples
+//! ```no_run
+//! # #[cfg(feature = "sqlx-postgres")] {
+//! # use ulys::Ulys;
+//! # async fn example(pool: sqlx::PgPool, id: Ulys) -> Result<(), sqlx::Error> {
+//! let found: Option<Ulys> = sqlx::query_scalar("SELECT id FROM t WHERE id = $1")
+//!     .bind(id)
+//!     .fetch_optional(&pool)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! # }
+//! ```
+
+use crate::Ulys;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+#[cfg(feature = "sqlx-postgres")]
+use sqlx::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef};
+#[cfg(feature = "sqlx-sqlite")]
+use sqlx::sqlite::{Sqlite, SqliteArgumentsBuffer, SqliteTypeInfo, SqliteValueRef};
+#[cfg(feature = "sqlx-postgres")]
+use sqlx::Postgres;
+use sqlx::{Decode, Encode, Type};
+#[cfg(feature = "sqlx-postgres")]
+use uuid::Uuid;
+
+#[cfg(feature = "sqlx-postgres")]
+impl Type<Postgres> for Ulys {
+	fn type_info() -> PgTypeInfo {
+		<Uuid as Type<Postgres>>::type_info()
+	}
+
+	fn compatible(ty: &PgTypeInfo) -> bool {
+		<Uuid as Type<Postgres>>::compatible(ty) || <[u8] as Type<Postgres>>::compatible(ty)
+	}
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl PgHasArrayType for Ulys {
+	fn array_type_info() -> PgTypeInfo {
+		<Uuid as PgHasArrayType>::array_type_info()
+	}
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl Encode<'_, Postgres> for Ulys {
+	fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+		<Uuid as Encode<Postgres>>::encode(Uuid::from(*self), buf)
+	}
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl Decode<'_, Postgres> for Ulys {
+	fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+		<Uuid as Decode<Postgres>>::decode(value).map(Ulys::from)
+	}
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl Type<Sqlite> for Ulys {
+	fn type_info() -> SqliteTypeInfo {
+		<[u8] as Type<Sqlite>>::type_info()
+	}
+
+	fn compatible(ty: &SqliteTypeInfo) -> bool {
+		<[u8] as Type<Sqlite>>::compatible(ty) || <str as Type<Sqlite>>::compatible(ty)
+	}
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl Encode<'_, Sqlite> for Ulys {
+	fn encode_by_ref(&self, buf: &mut SqliteArgumentsBuffer) -> Result<IsNull, BoxDynError> {
+		<Vec<u8> as Encode<Sqlite>>::encode(self.to_bytes().to_vec(), buf)
+	}
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl<'r> Decode<'r, Sqlite> for Ulys {
+	fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+		// `SQLite` hands out the bytes of `TEXT` values as blobs too.
+		let bytes = <&[u8] as Decode<Sqlite>>::decode(value)?;
+
+		if bytes.len() == Ulys::ULYS_LEN {
+			Ok(Ulys::from_ascii_bytes(bytes)?)
+		} else {
+			Ok(Ulys::try_from(bytes)?)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[cfg(feature = "sqlx-postgres")]
+	#[test]
+	fn postgres_encode() {
+		let ulys = Ulys::new();
+		let mut buf = PgArgumentBuffer::default();
+
+		assert!(matches!(
+			Encode::<Postgres>::encode_by_ref(&ulys, &mut buf),
+			Ok(IsNull::No)
+		));
+		assert_eq!(buf[..], ulys.to_bytes());
+
+		assert!(<Ulys as Type<Postgres>>::compatible(&<Uuid as Type<
+			Postgres,
+		>>::type_info()));
+		assert!(<Ulys as Type<Postgres>>::compatible(&<Vec<u8> as Type<
+			Postgres,
+		>>::type_info()));
+		assert!(!<Ulys as Type<Postgres>>::compatible(&<String as Type<
+			Postgres,
+		>>::type_info()));
+	}
+
+	#[cfg(feature = "sqlx-postgres")]
+	#[tokio::test]
+	#[ignore = "needs a Postgres server at ULYS_TEST_POSTGRES_URL"]
+	async fn postgres_cycle() {
+		use sqlx::{Connection, PgConnection};
+
+		let url = std::env::var("ULYS_TEST_POSTGRES_URL").unwrap();
+		let mut conn = PgConnection::connect(&url).await.unwrap();
+		sqlx::query("CREATE TEMPORARY TABLE ids (id uuid, raw bytea)")
+			.execute(&mut conn)
+			.await
+			.unwrap();
+
+		let ulys = Ulys::new();
+		sqlx::query("INSERT INTO ids (id, raw) VALUES ($1, $2)")
+			.bind(ulys)
+			.bind(ulys.to_bytes().to_vec())
+			.execute(&mut conn)
+			.await
+			.unwrap();
+
+		let (id, raw): (Ulys, Ulys) = sqlx::query_as("SELECT id, raw FROM ids WHERE id = $1")
+			.bind(ulys)
+			.fetch_one(&mut conn)
+			.await
+			.unwrap();
+		assert_eq!(id, ulys);
+		assert_eq!(raw, ulys);
+
+		let invalid = sqlx::query_scalar::<_, Ulys>("SELECT '\\x0102'::bytea")
+			.fetch_one(&mut conn)
+			.await;
+		assert!(invalid.is_err());
+	}
+
+	#[cfg(feature = "sqlx-sqlite")]
+	#[tokio::test]
+	async fn sqlite_cycle() {
+		let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+		sqlx::query("CREATE TABLE t (id BLOB, text TEXT)")
+			.execute(&pool)
+			.await
+			.unwrap();
+
+		let ulys = Ulys::new();
+		sqlx::query("INSERT INTO t (id, text) VALUES ($1, $2)")
+			.bind(ulys)
+			.bind(ulys.to_string())
+			.execute(&pool)
+			.await
+			.unwrap();
+
+		let (id, text): (Ulys, Ulys) = sqlx::query_as("SELECT id, text FROM t WHERE id = $1")
+			.bind(ulys)
+			.fetch_one(&pool)
+			.await
+			.unwrap();
+		assert_eq!(id, ulys);
+		assert_eq!(text, ulys);
+
+		let length: i64 = sqlx::query_scalar("SELECT length(id) FROM t")
+			.fetch_one(&pool)
+			.await
+			.unwrap();
+		assert_eq!(length, 16);
+
+		let invalid = sqlx::query_scalar::<_, Ulys>("SELECT x'0102'")
+			.fetch_one(&pool)
+			.await;
+		assert!(invalid.is_err());
+	}
+}
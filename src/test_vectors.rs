@@ -0,0 +1,108 @@
+//! Canonical test vectors for implementations of the Ulys format.
+
+/// Known Ulys values with their string encoding and components
+///
+/// Each entry is `(value, string, timestamp_ms, random, checksum)`, where the
+/// checksum is the stored lowest 32 bits. Ports of Ulys to other languages
+/// can check their encoder, decoder and field extraction against these.
+/// Most entries carry a valid checksum; the others, commented as not valid,
+/// exercise decoding and the rejection of invalid checksums.
+// cSpell:disable
+pub const TEST_VECTORS: &[(u128, &str, u64, u64, u32)] = &[
+	// nil, not valid
+	(
+		0x0000_0000_0000_0000_0000_0000_0000_0000,
+		"00000000000000000000000000",
+		0,
+		0x0000_0000_0000,
+		0x0000_0000,
+	),
+	// valid
+	(
+		0x0000_0000_0000_0000_0000_0000_d0a6_6a65,
+		"0000000000000000000d19kacm",
+		0,
+		0x0000_0000_0000,
+		0xd0a6_6a65,
+	),
+	// valid
+	(
+		0x0000_0000_0000_0000_0000_0001_f901_303c,
+		"0000000000000000000zj09g7g",
+		0,
+		0x0000_0000_0001,
+		0xf901_303c,
+	),
+	// valid
+	(
+		0x018b_cfe5_6800_1234_5678_9abc_127f_8e3e,
+		"065wzsb800938nkrkay14zwe7r",
+		1_700_000_000_000,
+		0x1234_5678_9abc,
+		0x127f_8e3e,
+	),
+	// not valid
+	(
+		0x0190_c5f6_cc0f_3c96_8369_82e3_8800_0206,
+		"068cbxpc1wy9d0v9gbhrg0020r",
+		1_721_308_204_047,
+		0x3c96_8369_82e3,
+		0x8800_0206,
+	),
+	// valid
+	(
+		0x0190_d9f2_951a_8840_c040_a7d7_9a7a_655a,
+		"068dkwmn3a441g20mzbsmyk5b8",
+		1_721_643_472_154,
+		0x8840_c040_a7d7,
+		0x9a7a_655a,
+	),
+	// not valid
+	(
+		0x881a_3bfe_01e9_4438_a68e_b1e7_e82b_7f9c,
+		"h0d3qzg1x523h9mep7kygavzkg",
+		149_646_257_029_609,
+		0x4438_a68e_b1e7,
+		0xe82b_7f9c,
+	),
+	// valid
+	(
+		0xffff_ffff_ffff_ffff_ffff_ffff_ca49_db07,
+		"zzzzzzzzzzzzzzzzzzzwmjev0w",
+		281_474_976_710_655,
+		0xffff_ffff_ffff,
+		0xca49_db07,
+	),
+	// not valid
+	(
+		0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff,
+		"zzzzzzzzzzzzzzzzzzzzzzzzzw",
+		281_474_976_710_655,
+		0xffff_ffff_ffff,
+		0xffff_ffff,
+	),
+];
+// cSpell:enable
+
+#[cfg(test)]
+mod tests {
+	use super::TEST_VECTORS;
+	use crate::Ulys;
+
+	#[test]
+	fn test_vectors_agree() {
+		for &(value, string, timestamp_ms, random, checksum) in TEST_VECTORS {
+			let ulys = Ulys(value);
+
+			assert_eq!(ulys.to_string(), string);
+			assert_eq!(Ulys::from_string(string), Ok(ulys));
+			assert_eq!(ulys.timestamp_ms(), timestamp_ms);
+			assert_eq!(ulys.random(), random);
+			assert_eq!(ulys.checksum(), checksum);
+			assert_eq!(
+				ulys.is_valid(),
+				Ulys::from_parts(timestamp_ms, random) == ulys
+			);
+		}
+	}
+}
@@ -0,0 +1,97 @@
+//! Conversions between ULYS and `time` datetimes.
+
+use crate::Ulys;
+use time::{OffsetDateTime, UtcDateTime};
+
+impl Ulys {
+	/// Creates a new Ulys with the given `time` datetime
+	///
+	/// The datetime is truncated to the millisecond and clamped like
+	/// `SystemTime` datetimes: datetimes before the Unix epoch are stored as
+	/// the epoch itself, and datetimes past the 48-bit millisecond range are
+	/// stored as the largest timestamp. The offset is irrelevant, only the
+	/// instant is stored.
+	#[must_use]
+	pub fn from_offset_datetime(datetime: OffsetDateTime) -> Ulys {
+		let millis = u64::try_from(datetime.unix_timestamp_nanos() / 1_000_000).unwrap_or(0);
+
+		Ulys::from_timestamp(millis.min(Ulys::TIME_MAX))
+	}
+
+	/// Gets the `time` datetime of when this Ulys was created accurate to
+	/// 1ms, in UTC
+	///
+	/// Unless the `large-dates` feature of `time` is enabled, datetimes are
+	/// limited to the year 9999, so timestamps past it are clamped to the
+	/// largest datetime.
+	#[must_use]
+	pub fn to_offset_datetime(&self) -> OffsetDateTime {
+		let nanos = i128::from(self.timestamp_ms()) * 1_000_000;
+
+		OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap_or(UtcDateTime::MAX.into())
+	}
+}
+
+impl From<OffsetDateTime> for Ulys {
+	fn from(datetime: OffsetDateTime) -> Self {
+		Ulys::from_offset_datetime(datetime)
+	}
+}
+
+impl From<Ulys> for OffsetDateTime {
+	fn from(ulys: Ulys) -> Self {
+		ulys.to_offset_datetime()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use time::{Duration, UtcOffset};
+
+	#[test]
+	fn time_cycle() {
+		let datetime =
+			OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_123_000_000).unwrap();
+		let ulys = Ulys::from_offset_datetime(datetime);
+
+		assert_eq!(ulys.timestamp_ms(), 1_700_000_000_123);
+		assert_eq!(ulys.to_offset_datetime(), datetime);
+		assert_eq!(OffsetDateTime::from(ulys), datetime);
+		assert_eq!(OffsetDateTime::from(ulys.datetime()), datetime);
+		assert!(Ulys::from(datetime).is_valid());
+
+		// Only the instant is stored.
+		let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+		let shifted = Ulys::from_offset_datetime(datetime.to_offset(offset));
+		assert_eq!(shifted.timestamp_ms(), ulys.timestamp_ms());
+	}
+
+	#[test]
+	fn time_truncates_sub_milliseconds() {
+		let datetime =
+			OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_123_999_999).unwrap();
+		let ulys = Ulys::from_offset_datetime(datetime);
+
+		assert_eq!(ulys.timestamp_ms(), 1_700_000_000_123);
+		assert_eq!(
+			ulys.to_offset_datetime(),
+			datetime - Duration::nanoseconds(999_999)
+		);
+	}
+
+	#[test]
+	fn time_clamps_to_range() {
+		let before_epoch = OffsetDateTime::UNIX_EPOCH - Duration::days(1);
+		assert_eq!(
+			Ulys::from_offset_datetime(before_epoch).to_offset_datetime(),
+			OffsetDateTime::UNIX_EPOCH
+		);
+
+		let max = Ulys::from_parts(Ulys::TIME_MAX, 0);
+		assert_eq!(
+			max.to_offset_datetime(),
+			OffsetDateTime::from(UtcDateTime::MAX)
+		);
+	}
+}
@@ -0,0 +1,65 @@
+//! Access to the current time, with an optional mock for tests.
+//!
+//! Every Ulys created "with the current time" reads the clock through
+//! [`now`]. With the `test-util` feature, [`set_mock_now`] pins the value it
+//! returns so tests can place IDs in exact milliseconds instead of relying on
+//! wall-clock spacing.
+
+use std::time::SystemTime;
+
+#[cfg(any(test, feature = "test-util"))]
+std::thread_local! {
+	static MOCK_NOW: core::cell::Cell<Option<SystemTime>> = const { core::cell::Cell::new(None) };
+}
+
+/// Gets the current time, or the mocked time set on this thread by
+/// [`set_mock_now`]
+#[must_use]
+pub fn now() -> SystemTime {
+	#[cfg(any(test, feature = "test-util"))]
+	if let Some(mocked) = MOCK_NOW.get() {
+		return mocked;
+	}
+
+	SystemTime::now()
+}
+
+/// Makes [`now`] return `datetime` on the current thread, until
+/// [`clear_mock_now`] is called
+///
+/// The mock is thread-local, so tests running in parallel do not interfere
+/// with each other, but IDs generated on other threads keep reading the real
+/// clock.
+#[cfg(any(test, feature = "test-util"))]
+pub fn set_mock_now(datetime: SystemTime) {
+	MOCK_NOW.set(Some(datetime));
+}
+
+/// Makes [`now`] read the real clock again on the current thread
+#[cfg(any(test, feature = "test-util"))]
+pub fn clear_mock_now() {
+	MOCK_NOW.set(None);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Ulys;
+	use std::time::Duration;
+
+	#[test]
+	fn test_mock_now() {
+		let datetime = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+		set_mock_now(datetime);
+		let ulys1 = Ulys::new();
+		set_mock_now(datetime + Duration::from_millis(1));
+		let ulys2 = Ulys::new();
+		clear_mock_now();
+
+		assert_eq!(ulys1.datetime(), datetime);
+		assert_eq!(ulys2.timestamp_delta_ms(&ulys1), 1);
+		assert!(ulys1 < ulys2);
+		assert!(Ulys::new().datetime() > datetime);
+	}
+}
@@ -0,0 +1,72 @@
+//! ULYSes paired with the high-resolution instant of their creation.
+
+use crate::Ulys;
+use std::time::{Duration, Instant};
+
+/// A Ulys paired with the monotonic `Instant` at which it was generated.
+///
+/// The timestamp embedded in a Ulys only has millisecond resolution and comes
+/// from the wall clock, so it cannot measure short latencies. The `created`
+/// instant can, and is unaffected by wall clock adjustments. It is only
+/// meaningful within the process that generated the Ulys.
+///
+/// # Examples
+/// ```
+/// # use ulys::Ulys;
+/// let timed = Ulys::new_timed();
+///
+/// // ... handle the request ...
+///
+/// println!("request {} took {:?}", timed.id, timed.elapsed());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct TimedUlys {
+	/// The generated Ulys
+	pub id: Ulys,
+	/// The instant at which the Ulys was generated
+	pub created: Instant,
+}
+
+impl TimedUlys {
+	/// Gets the time elapsed since the Ulys was generated
+	#[must_use]
+	pub fn elapsed(&self) -> Duration {
+		self.created.elapsed()
+	}
+}
+
+impl From<TimedUlys> for Ulys {
+	fn from(timed: TimedUlys) -> Self {
+		timed.id
+	}
+}
+
+impl Ulys {
+	/// Creates a new Ulys with the current time (UTC), along with the
+	/// current `Instant`
+	#[must_use]
+	pub fn new_timed() -> TimedUlys {
+		TimedUlys {
+			id: Ulys::new(),
+			created: Instant::now(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::thread;
+
+	#[test]
+	fn timed_elapsed_increases() {
+		let timed = Ulys::new_timed();
+		let before = timed.elapsed();
+		thread::sleep(Duration::from_millis(5));
+		let after = timed.elapsed();
+
+		assert!(timed.id.is_valid());
+		assert!(after >= before + Duration::from_millis(5));
+		assert_eq!(Ulys::from(timed), timed.id);
+	}
+}
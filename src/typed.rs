@@ -0,0 +1,177 @@
+//! Ulys identifiers tagged with a compile-time domain.
+
+use crate::{Ulys, UlysError};
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::str::FromStr;
+
+/// A Ulys tagged with a marker type so identifiers of different domains
+/// cannot be mixed up.
+///
+/// The tag only exists at compile time: a `TypedUlys<T>` has the same size,
+/// ordering, string form and serialization as the plain [`Ulys`] it wraps.
+///
+/// # Examples
+/// ```
+/// # use ulys::{TypedUlys, Ulys};
+/// struct User;
+/// type UserId = TypedUlys<User>;
+///
+/// let id = UserId::new();
+/// let parsed: UserId = id.to_string().parse().unwrap();
+/// assert_eq!(id, parsed);
+/// assert!(parsed.is_valid());
+/// ```
+///
+/// Identifiers of different domains are distinct types:
+/// ```compile_fail
+/// # use ulys::TypedUlys;
+/// struct User;
+/// struct Order;
+///
+/// let user: TypedUlys<User> = TypedUlys::new();
+/// let order: TypedUlys<Order> = user;
+/// ```
+pub struct TypedUlys<T>(Ulys, PhantomData<fn() -> T>);
+
+impl<T> TypedUlys<T> {
+	/// Creates a new tagged Ulys with the current time (UTC)
+	#[cfg(feature = "std")]
+	#[must_use]
+	pub fn new() -> Self {
+		Self::from_ulys(Ulys::new())
+	}
+
+	/// Tags an existing Ulys
+	#[must_use]
+	pub const fn from_ulys(ulys: Ulys) -> Self {
+		TypedUlys(ulys, PhantomData)
+	}
+
+	/// Gets the untagged Ulys
+	#[must_use]
+	pub const fn into_ulys(self) -> Ulys {
+		self.0
+	}
+}
+
+impl<T> Deref for TypedUlys<T> {
+	type Target = Ulys;
+
+	fn deref(&self) -> &Ulys {
+		&self.0
+	}
+}
+
+impl<T> From<Ulys> for TypedUlys<T> {
+	fn from(ulys: Ulys) -> Self {
+		Self::from_ulys(ulys)
+	}
+}
+
+impl<T> From<TypedUlys<T>> for Ulys {
+	fn from(typed: TypedUlys<T>) -> Self {
+		typed.0
+	}
+}
+
+impl<T> FromStr for TypedUlys<T> {
+	type Err = UlysError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Ulys::from_string(s).map(Self::from_ulys)
+	}
+}
+
+impl<T> fmt::Display for TypedUlys<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.0, f)
+	}
+}
+
+impl<T> fmt::Debug for TypedUlys<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Debug::fmt(&self.0, f)
+	}
+}
+
+// The trait implementations below are written by hand because derives would
+// require the marker type to implement them too.
+
+impl<T> Default for TypedUlys<T> {
+	fn default() -> Self {
+		Self::from_ulys(Ulys::default())
+	}
+}
+
+impl<T> Clone for TypedUlys<T> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T> Copy for TypedUlys<T> {}
+
+impl<T> PartialEq for TypedUlys<T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl<T> Eq for TypedUlys<T> {}
+
+impl<T> PartialOrd for TypedUlys<T> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T> Ord for TypedUlys<T> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.0.cmp(&other.0)
+	}
+}
+
+impl<T> Hash for TypedUlys<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.hash(state);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	struct User;
+	struct Order;
+
+	#[test]
+	fn typed_cycle() {
+		let user = TypedUlys::<User>::new();
+		let text = user.to_string();
+		let parsed: TypedUlys<User> = text.parse().unwrap();
+
+		assert_eq!(user, parsed);
+		assert_eq!(text, user.into_ulys().to_string());
+		assert_eq!(user.timestamp_ms(), parsed.timestamp_ms());
+	}
+
+	#[test]
+	fn typed_retag_explicitly() {
+		let user = TypedUlys::<User>::new();
+		let order = TypedUlys::<Order>::from_ulys(user.into_ulys());
+
+		assert_eq!(Ulys::from(user), Ulys::from(order));
+		assert_eq!(size_of::<TypedUlys<User>>(), size_of::<Ulys>());
+	}
+
+	#[test]
+	fn typed_invalid_string() {
+		let parsed = "ABC".parse::<TypedUlys<User>>();
+
+		assert_eq!(parsed.unwrap_err(), UlysError::ParseInvalidLength);
+	}
+}
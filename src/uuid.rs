@@ -1,14 +1,89 @@
 //! Conversions between ULYS and UUID.
 
-use crate::Ulys;
-use uuid::Uuid;
+use crate::{Ulys, UlysError};
+use uuid::{Uuid, Variant};
 
+impl Ulys {
+	/// Converts this Ulys to a UUID that passes version and variant checks
+	///
+	/// The `From` conversion keeps the bits as they are, so the resulting UUID
+	/// usually carries an arbitrary version and variant that strict consumers
+	/// reject. This conversion only succeeds when the bits happen to form the
+	/// RFC 4122 variant with a version between 1 and 8, so callers know up
+	/// front whether the UUID will be accepted.
+	///
+	/// # Errors
+	///
+	/// An `UlysError::InvalidUuid` will be returned when the UUID would not
+	/// have the RFC 4122 variant and a known version.
+	pub fn try_to_uuid(&self) -> Result<Uuid, UlysError> {
+		let uuid = Uuid::from(*self);
+
+		if uuid.get_variant() == Variant::RFC4122 && (1..=8).contains(&uuid.get_version_num()) {
+			Ok(uuid)
+		} else {
+			Err(UlysError::InvalidUuid)
+		}
+	}
+
+	/// Converts this Ulys to a well-formed RFC 9562 version 7 UUID
+	///
+	/// Unlike the `From` conversion, which keeps the bits as they are, this
+	/// lays the Ulys out as a version 7 UUID: the 48-bit timestamp fills the
+	/// `unix_ts_ms` field, the version and variant bits are set, and the 48
+	/// random bits follow in `rand_a` and `rand_b`. The checksum does not
+	/// fit and the last 26 bits of `rand_b` are zero. These UUIDs sort by
+	/// time like ULYSes, and [`Ulys::from_uuid_v7`] converts them back.
+	#[must_use]
+	pub fn to_uuid_v7(&self) -> Uuid {
+		let random = u128::from(self.random());
+		let rand_a = random >> 36;
+		let rand_b = (random & 0xf_ffff_ffff) << 26;
+
+		Uuid::from_u128(
+			u128::from(self.timestamp_ms()) << 80 | 0x7 << 76 | rand_a << 64 | 0b10 << 62 | rand_b,
+		)
+	}
+
+	/// Creates a Ulys from a version 7 UUID created by [`Ulys::to_uuid_v7`]
+	///
+	/// The timestamp and the 48 random bits are read back from their UUID
+	/// fields and the checksum is computed again, so converting a valid Ulys
+	/// to a version 7 UUID and back gives the same Ulys. Any other version 7
+	/// UUID gives the Ulys with the same timestamp and the first 48 of its
+	/// random bits.
+	///
+	/// # Errors
+	///
+	/// An `UlysError::InvalidUuid` will be returned when the UUID is not a
+	/// version 7 UUID with the RFC 9562 variant.
+	pub fn from_uuid_v7(uuid: Uuid) -> Result<Ulys, UlysError> {
+		if uuid.get_variant() != Variant::RFC4122 || uuid.get_version_num() != 7 {
+			return Err(UlysError::InvalidUuid);
+		}
+
+		let uuid = uuid.as_u128();
+		// Both portions are masked to their 48 bits.
+		#[allow(clippy::cast_possible_truncation)]
+		let (timestamp, random) = (
+			(uuid >> 80) as u64,
+			((uuid >> 64 & 0xfff) << 36 | (uuid >> 26 & 0xf_ffff_ffff)) as u64,
+		);
+
+		Ok(Ulys::from_parts(timestamp, random))
+	}
+}
+
+/// Reinterprets the bits of a UUID as a Ulys, see [`Ulys::from_uuid_v7`] to
+/// convert a version 7 UUID instead.
 impl From<Uuid> for Ulys {
 	fn from(uuid: Uuid) -> Self {
 		Ulys(uuid.as_u128())
 	}
 }
 
+/// Reinterprets the bits of a Ulys as a UUID, which is usually not a
+/// well-formed UUID, see [`Ulys::to_uuid_v7`] to get one.
 impl From<Ulys> for Uuid {
 	fn from(ulys: Ulys) -> Self {
 		Uuid::from_u128(ulys.0)
@@ -18,6 +93,7 @@ impl From<Ulys> for Uuid {
 #[cfg(test)]
 mod test {
 	use super::*;
+	use uuid::Version;
 
 	#[test]
 	fn uuid_cycle() {
@@ -40,4 +116,62 @@ mod test {
 		let uuid: Uuid = ulys.into();
 		assert_eq!(uuid.to_string(), uuid_txt);
 	}
+
+	#[test]
+	fn uuid_v7_cycle() {
+		let ulys = Ulys::from_parts(0x0123_4567_89ab, 0xfedc_ba98_7654);
+		let uuid = ulys.to_uuid_v7();
+
+		assert_eq!(uuid.get_version(), Some(Version::SortRand));
+		assert_eq!(uuid.get_variant(), Variant::RFC4122);
+		assert_eq!(uuid.to_string(), "01234567-89ab-7fed-b2ea-61d950000000");
+		assert_eq!(ulys.try_to_uuid(), Err(UlysError::InvalidUuid));
+		assert_eq!(Ulys::from_uuid_v7(uuid), Ok(ulys));
+
+		let ulys = Ulys::new();
+		assert_eq!(Ulys::from_uuid_v7(ulys.to_uuid_v7()), Ok(ulys));
+		assert_eq!(
+			ulys.to_uuid_v7().as_u128() >> 80,
+			u128::from(ulys.timestamp_ms())
+		);
+		assert!(Ulys::from_parts(ulys.timestamp_ms() + 1, 0).to_uuid_v7() > ulys.to_uuid_v7());
+	}
+
+	#[test]
+	fn from_uuid_v7_invalid() {
+		// Version 4 with the RFC 4122 variant.
+		let uuid = Uuid::parse_str("881a3bfe-01e9-4438-a68e-b1e7e82b7f9c").unwrap();
+		assert_eq!(Ulys::from_uuid_v7(uuid), Err(UlysError::InvalidUuid));
+
+		// Version 7 with the Microsoft variant.
+		let uuid = Uuid::parse_str("01234567-89ab-7fed-c2ea-61d950000000").unwrap();
+		assert_eq!(Ulys::from_uuid_v7(uuid), Err(UlysError::InvalidUuid));
+	}
+
+	#[test]
+	fn try_to_uuid_valid() {
+		// Version 4 with the RFC 4122 variant.
+		let ulys: Ulys = Uuid::parse_str("881a3bfe-01e9-4438-a68e-b1e7e82b7f9c")
+			.unwrap()
+			.into();
+
+		assert_eq!(ulys.try_to_uuid(), Ok(Uuid::from(ulys)));
+	}
+
+	#[test]
+	fn try_to_uuid_invalid() {
+		// Version 4 with the Microsoft variant.
+		let ulys: Ulys = Uuid::parse_str("881a3bfe-01e9-4438-c68e-b1e7e82b7f9c")
+			.unwrap()
+			.into();
+		assert_eq!(ulys.try_to_uuid(), Err(UlysError::InvalidUuid));
+
+		// Version 0 with the RFC 4122 variant.
+		let ulys: Ulys = Uuid::parse_str("881a3bfe-01e9-0438-a68e-b1e7e82b7f9c")
+			.unwrap()
+			.into();
+		assert_eq!(ulys.try_to_uuid(), Err(UlysError::InvalidUuid));
+
+		assert_eq!(Ulys::default().try_to_uuid(), Err(UlysError::InvalidUuid));
+	}
 }
@@ -0,0 +1,91 @@
+//! Structured inspection of ULYSes with `valuable`.
+
+use crate::Ulys;
+use alloc::string::ToString;
+use valuable::{Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit};
+
+/// The fields of a Ulys, the same as its `Debug` output
+static FIELDS: &[NamedField<'static>] = &[
+	NamedField::new("str"),
+	NamedField::new("timestamp_ms"),
+	NamedField::new("random"),
+	NamedField::new("valid"),
+];
+
+/// Presents a Ulys as a `Ulys` structure with its string, timestamp, random
+/// portion and validity, so log processors can index on the timestamp
+/// without parsing the string.
+impl Valuable for Ulys {
+	fn as_value(&self) -> Value<'_> {
+		Value::Structable(self)
+	}
+
+	fn visit(&self, visitor: &mut dyn Visit) {
+		let string = self.to_string();
+
+		visitor.visit_named_fields(&NamedValues::new(
+			FIELDS,
+			&[
+				Value::String(&string),
+				Value::U64(self.timestamp_ms()),
+				Value::U64(self.random()),
+				Value::Bool(self.is_valid()),
+			],
+		));
+	}
+}
+
+impl Structable for Ulys {
+	fn definition(&self) -> StructDef<'_> {
+		StructDef::new_static("Ulys", Fields::Named(FIELDS))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Records the fields visited, as their `Debug` output
+	#[derive(Default)]
+	struct FieldRecorder(Vec<(String, String)>);
+
+	impl Visit for FieldRecorder {
+		fn visit_value(&mut self, value: Value<'_>) {
+			if let Value::Structable(structable) = value {
+				structable.visit(self);
+			}
+		}
+
+		fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+			for (field, value) in named_values {
+				self.0
+					.push((field.name().to_string(), format!("{value:?}")));
+			}
+		}
+	}
+
+	#[test]
+	fn valuable_fields() {
+		let ulys = Ulys::from_parts(1_700_000_000_000, 0x1234_5678_9abc);
+		let mut recorder = FieldRecorder::default();
+		valuable::visit(&ulys, &mut recorder);
+
+		// cSpell:disable
+		assert_eq!(
+			recorder.0,
+			[
+				("str", "\"065wzsb800938nkrkay14zwe7r\""),
+				("timestamp_ms", "1700000000000"),
+				("random", "20015998343868"),
+				("valid", "true"),
+			]
+			.map(|(name, value)| (name.to_string(), value.to_string()))
+		);
+		// cSpell:enable
+
+		let Value::Structable(structable) = ulys.as_value() else {
+			panic!("a Ulys is a structure");
+		};
+		assert_eq!(structable.definition().name(), "Ulys");
+	}
+}
@@ -0,0 +1,87 @@
+//! Zeroization of ULYSes used as secrets.
+//!
+//! `Ulys` is `Copy`, so zeroizing one value does not erase the copies made
+//! along the way. Keep secret identifiers in a [`ZeroizingUlys`], which is
+//! not `Copy` and wipes its memory when dropped.
+
+use crate::Ulys;
+use core::ops::Deref;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+impl Zeroize for Ulys {
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+/// A ULYS that is zeroized when dropped.
+///
+/// # Examples
+/// ```
+/// # use ulys::Ulys;
+/// # use ulys::zeroize::ZeroizingUlys;
+/// let token = ZeroizingUlys::new(Ulys::new());
+/// assert!(token.is_valid());
+/// ```
+#[derive(PartialEq, Eq)]
+pub struct ZeroizingUlys(Ulys);
+
+impl ZeroizingUlys {
+	/// Wraps a ULYS so it is zeroized on drop
+	#[must_use]
+	pub fn new(ulys: Ulys) -> Self {
+		ZeroizingUlys(ulys)
+	}
+}
+
+impl Deref for ZeroizingUlys {
+	type Target = Ulys;
+
+	fn deref(&self) -> &Ulys {
+		&self.0
+	}
+}
+
+impl From<Ulys> for ZeroizingUlys {
+	fn from(ulys: Ulys) -> Self {
+		ZeroizingUlys(ulys)
+	}
+}
+
+impl Zeroize for ZeroizingUlys {
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+impl Drop for ZeroizingUlys {
+	fn drop(&mut self) {
+		self.zeroize();
+	}
+}
+
+impl ZeroizeOnDrop for ZeroizingUlys {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn zeroize_sets_nil() {
+		let mut ulys = Ulys::new();
+		assert!(!ulys.is_default());
+
+		ulys.zeroize();
+		assert!(ulys.is_default());
+	}
+
+	#[test]
+	fn zeroizing_wrapper() {
+		let ulys = Ulys::new();
+		let mut token = ZeroizingUlys::new(ulys);
+		assert_eq!(*token, ulys);
+
+		token.zeroize();
+		assert!(token.is_default());
+	}
+}